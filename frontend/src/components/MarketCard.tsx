@@ -7,7 +7,12 @@ import {
 	formatDate,
 	convertTimestamp,
 } from "../lib/helpers";
-import { fetchMarketAccount, fetchPositionAccount } from "../lib/program/utils";
+import {
+	fetchMarketAccount,
+	fetchPositionAccount,
+	isMarketApproved,
+	isMarketResolved,
+} from "../lib/program/utils";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { useTimeSync } from "../context/TimeSyncProvider";
 
@@ -49,7 +54,7 @@ const MarketCard: React.FC<MarketCardProps> = ({
 		if (
 			panelType === "UnclaimedRewards" &&
 			userPosition &&
-			market?.state.isResolved &&
+			isMarketResolved(market?.state) &&
 			!!userPosition.reward
 		) {
 			claimableText = `Reward: ${formatCurrency(
@@ -87,7 +92,7 @@ const MarketCard: React.FC<MarketCardProps> = ({
 					}}
 					disabled={
 						userContextData.isTransactionPending ||
-						!currentMarket?.state.isResolved
+						!isMarketResolved(currentMarket?.state)
 					}
 					className="bg-lime-500 hover:bg-lime-600 text-white px-4 py-3 rounded-md text-sm font-medium transition-colors duration-200 disabled:bg-gray-400 disabled:cursor-not-allowed disabled:opacity-80"
 				>
@@ -150,13 +155,13 @@ const MarketCard: React.FC<MarketCardProps> = ({
 							</div>
 						</div>
 
-						{currentMarket?.state.isResolved &&
-							currentMarket?.state.resolution !== undefined && (
+						{isMarketResolved(currentMarket?.state) &&
+							currentMarket?.state.resolution && (
 								<div className="bg-gray-50 p-3 rounded-lg mb-3">
 									<div className="text-sm text-gray-600">
 										Resolution:{" "}
 										<span className="font-medium text-green-700">
-											{currentMarket?.state.resolution}
+											{currentMarket.state.resolution.toString()}
 										</span>
 									</div>
 
@@ -236,7 +241,7 @@ const MarketCard: React.FC<MarketCardProps> = ({
 								</div>
 							</div>
 
-							{currentMarket?.state.isApproved && (
+							{isMarketApproved(currentMarket?.state) && (
 								<div className="flex items-center text-lime-600">
 									<Clock className="w-4 h-4 mr-1" />
 									{new Date() <
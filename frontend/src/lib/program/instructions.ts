@@ -1,7 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
 import {
-	clusterApiUrl,
-	Connection,
 	LAMPORTS_PER_SOL,
 	PublicKey,
 	TransactionInstruction,
@@ -10,23 +8,36 @@ import {
 } from "@solana/web3.js";
 
 import {
-	calculateTotalScores,
 	connection,
+	EVENT_AUTHORITY,
 	getMarketConfigPDA,
 	getMarketStatePDA,
+	getMarketTombstonePDA,
 	getMarketVaultPDA,
 	getPositionPDA,
 	PLATFORM_CONFIG,
 	PLATFORM_TREASURY,
 	program,
 } from "./utils";
+import type { CurneyMarkets } from "./types";
 
 const SYSTEM_PROGRAM_ID = anchor.web3.SystemProgram.programId;
 
 const RENT_SYSVAR_ACCOUNT = anchor.web3.SYSVAR_RENT_PUBKEY;
+
+// Scoring settings for markets proposed from the app, which only takes SOL stakes
+const DEFAULT_SIGMA_MIN = 5;
+const DEFAULT_SIGMA_MAX = 50;
+const DEFAULT_RESOLUTION_WINDOW = 7 * 24 * 60 * 60; // 7 days after the end time
+
+// Positions scored per settlement transaction, to stay within the size limit
+const SETTLEMENT_BATCH_SIZE = 20;
+
+type DismissReason = anchor.IdlTypes<CurneyMarkets>["dismissReason"];
+
 interface UpdateMarketConfigParams {
 	marketConfig: string;
-	admin: PublicKey;
+	authority: PublicKey;
 	startTime?: number;
 	endTime?: number;
 	minPredictionPrice?: number;
@@ -35,7 +46,7 @@ interface UpdateMarketConfigParams {
 }
 
 interface UpdatePlatformConfigParams {
-	admin: PublicKey;
+	authority: PublicKey;
 	creatorFeeBps?: number;
 	platformFeeBps?: number;
 	marketProposalFee?: number;
@@ -66,24 +77,39 @@ export const proposeMarket = async (
 	const marketConfig = getMarketConfigPDA(marketId);
 	const marketState = getMarketStatePDA(marketConfig);
 	const marketVault = getMarketVaultPDA(marketConfig);
+	const marketTombstone = getMarketTombstonePDA(marketId);
 
 	const ix = await program.methods
 		.proposeMarket(
 			new anchor.BN(marketId),
 			new anchor.BN(startTime),
 			new anchor.BN(endTime),
+			new anchor.BN(endTime + DEFAULT_RESOLUTION_WINDOW),
 			new anchor.BN(minPredictionPrice * LAMPORTS_PER_SOL),
+			new anchor.BN(DEFAULT_SIGMA_MIN),
+			new anchor.BN(DEFAULT_SIGMA_MAX),
+			{ linear: {} },
+			{ gaussian: {} },
+			{ onChain: {} },
+			null, // Resolved by hand
+			new anchor.BN(0), // No creator bond
 			question,
 			description
 		)
 		.accountsStrict({
 			creator: user,
+			platformConfig: PLATFORM_CONFIG,
 			marketConfig,
 			marketState,
 			marketVault,
-			platformConfig: PLATFORM_CONFIG,
-			platformTreasury: PLATFORM_TREASURY,
+			marketTombstone,
+			stakeMint: null,
+			vaultTokenAccount: null,
+			tokenProgram: null,
+			associatedTokenProgram: null,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
@@ -92,7 +118,7 @@ export const proposeMarket = async (
 
 export const updateMarketConfig = async ({
 	marketConfig,
-	admin,
+	authority,
 	startTime,
 	endTime,
 	minPredictionPrice,
@@ -106,94 +132,160 @@ export const updateMarketConfig = async ({
 		.updateMarketConfig(
 			startTime ? new anchor.BN(startTime) : null,
 			endTime ? new anchor.BN(endTime) : null,
+			null,
 			minPredictionPrice
 				? new anchor.BN(minPredictionPrice * LAMPORTS_PER_SOL)
 				: null,
+			null,
+			null,
+			null,
+			null,
+			null,
 			question ? question : null,
 			description ? description : null
 		)
 		.accountsStrict({
-			admin,
+			authority,
+			platformConfig: PLATFORM_CONFIG,
 			marketConfig: marketConfigPubKey,
 			marketState,
-			platformConfig: PLATFORM_CONFIG,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
-	return await buildTransaction(admin, [ix]);
+	return await buildTransaction(authority, [ix]);
 };
 
-export const approveMarket = async (marketConfig: string, admin: PublicKey) => {
+export const approveMarket = async (
+	marketConfig: string,
+	revision: number,
+	approver: PublicKey
+) => {
 	const marketConfigPubKey = new PublicKey(marketConfig);
 	const marketState = getMarketStatePDA(marketConfigPubKey);
+	const marketVault = getMarketVaultPDA(marketConfigPubKey);
 
 	const ix = await program.methods
-		.approveMarket()
+		.approveMarket(null, revision)
 		.accountsStrict({
-			admin,
+			approver,
+			platformConfig: PLATFORM_CONFIG,
+			platformTreasury: PLATFORM_TREASURY,
 			marketConfig: marketConfigPubKey,
 			marketState,
-			platformConfig: PLATFORM_CONFIG,
+			marketVault,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
-	return await buildTransaction(admin, [ix]);
+	return await buildTransaction(approver, [ix]);
 };
 
 export const dismissMarket = async (
 	marketConfig: string,
+	marketId: string,
 	creator: string,
-	admin: PublicKey
+	reason: DismissReason,
+	approver: PublicKey
 ) => {
 	const marketConfigPubKey = new PublicKey(marketConfig);
 	const marketState = getMarketStatePDA(marketConfigPubKey);
 	const marketVault = getMarketVaultPDA(marketConfigPubKey);
+	const marketTombstone = getMarketTombstonePDA(marketId);
 	const creatorPubkey = new PublicKey(creator);
 
 	const ix = await program.methods
-		.dismissMarket()
+		.dismissMarket(reason)
 		.accountsStrict({
-			admin,
+			approver,
 			creator: creatorPubkey,
+			platformConfig: PLATFORM_CONFIG,
+			platformTreasury: PLATFORM_TREASURY,
 			marketConfig: marketConfigPubKey,
 			marketState,
 			marketVault,
-			platformConfig: PLATFORM_CONFIG,
-			platformTreasury: PLATFORM_TREASURY,
+			marketTombstone,
+			stakeMint: null,
+			vaultTokenAccount: null,
+			creatorTokenAccount: null,
+			tokenProgram: null,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
-	return await buildTransaction(admin, [ix]);
+	return await buildTransaction(approver, [ix]);
 };
 
 export const resolveMarket = async (
-	marketId: string,
+	marketConfig: string,
 	resolution: number,
-	admin: PublicKey
+	resolver: PublicKey
 ) => {
-	const marketConfig = getMarketConfigPDA(marketId);
-	const marketState = getMarketStatePDA(marketConfig);
-	const totalScores = await calculateTotalScores(
-		resolution,
-		program,
-		marketConfig
-	);
+	const marketConfigPubKey = new PublicKey(marketConfig);
+	const marketState = getMarketStatePDA(marketConfigPubKey);
 
 	const ix = await program.methods
-		.resolveMarket(new anchor.BN(resolution), totalScores)
+		.resolveMarket(new anchor.BN(resolution))
 		.accountsStrict({
-			admin,
-			marketConfig,
-			marketState,
+			resolver,
 			platformConfig: PLATFORM_CONFIG,
+			resolverCommittee: null,
+			marketConfig: marketConfigPubKey,
+			marketState,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
-	return await buildTransaction(admin, [ix]);
+	return await buildTransaction(resolver, [ix]);
+};
+
+export const settlePositions = async (
+	marketConfig: string,
+	payer: PublicKey
+) => {
+	const marketConfigPubKey = new PublicKey(marketConfig);
+	const marketState = getMarketStatePDA(marketConfigPubKey);
+	const { resolutionAmendments } = await program.account.marketState.fetch(
+		marketState
+	);
+
+	// Positions never scored, or scored before the latest amendment
+	const unscoredPositions = (await program.account.position.all())
+		.filter(
+			(p) =>
+				p.account.market.toBase58() == marketConfig &&
+				(p.account.score === null ||
+					p.account.scoredAmendment != resolutionAmendments)
+		)
+		.slice(0, SETTLEMENT_BATCH_SIZE);
+
+	const ix = await program.methods
+		.settlePositions()
+		.accountsStrict({
+			platformConfig: PLATFORM_CONFIG,
+			marketConfig: marketConfigPubKey,
+			marketState,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
+		})
+		.remainingAccounts(
+			unscoredPositions.map((p) => ({
+				pubkey: p.publicKey,
+				isSigner: false,
+				isWritable: true,
+			}))
+		)
+		.instruction();
+
+	return await buildTransaction(payer, [ix]);
 };
 
 export const placePrediction = async (
@@ -215,13 +307,18 @@ export const placePrediction = async (
 		)
 		.accountsStrict({
 			user,
-			position,
+			platformConfig: PLATFORM_CONFIG,
 			marketConfig: marketConfigPubKey,
 			marketState,
 			marketVault,
-			platformConfig: PLATFORM_CONFIG,
-			platformTreasury: PLATFORM_TREASURY,
+			position,
+			stakeMint: null,
+			userTokenAccount: null,
+			vaultTokenAccount: null,
+			tokenProgram: null,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
@@ -242,12 +339,18 @@ export const claimReward = async (
 		.claimReward()
 		.accountsStrict({
 			user,
-			position: positionPubkey,
+			platformConfig: PLATFORM_CONFIG,
 			marketConfig: marketConfigPubkey,
 			marketState,
 			marketVault,
-			platformConfig: PLATFORM_CONFIG,
+			position: positionPubkey,
+			stakeMint: null,
+			vaultTokenAccount: null,
+			userTokenAccount: null,
+			tokenProgram: null,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
@@ -266,35 +369,77 @@ export const withdrawCreatorRevenue = async (
 		.withdrawCreatorRevenue()
 		.accountsStrict({
 			creator,
+			platformConfig: PLATFORM_CONFIG,
 			marketConfig: marketConfigPubKey,
 			marketState,
 			marketVault,
-			platformConfig: PLATFORM_CONFIG,
+			stakeMint: null,
+			vaultTokenAccount: null,
+			creatorTokenAccount: null,
+			tokenProgram: null,
 			rent: RENT_SYSVAR_ACCOUNT,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
 	return await buildTransaction(creator, [ix]);
 };
 
-export const withdrawPlatformFees = async (admin: PublicKey) => {
+export const collectPlatformFees = async (
+	marketConfig: string,
+	treasurer: PublicKey
+) => {
+	const marketConfigPubKey = new PublicKey(marketConfig);
+	const marketState = getMarketStatePDA(marketConfigPubKey);
+	const marketVault = getMarketVaultPDA(marketConfigPubKey);
+
+	const ix = await program.methods
+		.collectPlatformFees()
+		.accountsStrict({
+			treasurer,
+			platformConfig: PLATFORM_CONFIG,
+			platformTreasury: PLATFORM_TREASURY,
+			marketConfig: marketConfigPubKey,
+			marketState,
+			marketVault,
+			stakeMint: null,
+			vaultTokenAccount: null,
+			treasuryTokenAccount: null,
+			tokenProgram: null,
+			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
+		})
+		.instruction();
+
+	return await buildTransaction(treasurer, [ix]);
+};
+
+export const withdrawPlatformFees = async (treasurer: PublicKey) => {
 	const ix = await program.methods
 		.withdrawPlatformFees()
 		.accountsStrict({
-			admin,
+			treasurer,
 			platformConfig: PLATFORM_CONFIG,
 			platformTreasury: PLATFORM_TREASURY,
+			stakeMint: null,
+			treasuryTokenAccount: null,
+			treasurerTokenAccount: null,
+			tokenProgram: null,
 			rent: RENT_SYSVAR_ACCOUNT,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
-	return await buildTransaction(admin, [ix]);
+	return await buildTransaction(treasurer, [ix]);
 };
 
 export const updatePlatformConfig = async ({
-	admin,
+	authority,
 	creatorFeeBps,
 	platformFeeBps,
 	marketProposalFee,
@@ -303,14 +448,24 @@ export const updatePlatformConfig = async ({
 		.updatePlatformConfig(
 			creatorFeeBps ? creatorFeeBps : null,
 			platformFeeBps ? platformFeeBps : null,
-			marketProposalFee ? new anchor.BN(marketProposalFee) : null
+			marketProposalFee ? new anchor.BN(marketProposalFee) : null,
+			null,
+			null,
+			null,
+			null,
+			null,
+			null,
+			null,
+			null
 		)
 		.accountsStrict({
-			admin,
+			authority,
 			platformConfig: PLATFORM_CONFIG,
 			systemProgram: SYSTEM_PROGRAM_ID,
+			eventAuthority: EVENT_AUTHORITY,
+			program: program.programId,
 		})
 		.instruction();
 
-	return await buildTransaction(admin, [ix]);
+	return await buildTransaction(authority, [ix]);
 };
@@ -14,16 +14,16 @@ export type CurneyMarkets = {
 	};
 	instructions: [
 		{
-			name: "approveMarket";
-			discriminator: [195, 83, 73, 224, 150, 237, 150, 5];
+			name: "acceptAdmin";
+			discriminator: [112, 42, 45, 90, 116, 181, 13, 170];
 			accounts: [
 				{
-					name: "admin";
-					writable: true;
+					name: "pendingAdmin";
 					signer: true;
 				},
 				{
 					name: "platformConfig";
+					writable: true;
 					pda: {
 						seeds: [
 							{
@@ -48,91 +48,53 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "admin";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "marketConfig";
+					name: "eventAuthority";
 					pda: {
 						seeds: [
 							{
 								kind: "const";
 								value: [
-									109,
-									97,
-									114,
-									107,
+									95,
+									95,
 									101,
-									116,
-									45,
-									99,
-									111,
-									110,
-									102,
-									105,
-									103
-								];
-							},
-							{
-								kind: "account";
-								path: "market_config.market_id";
-								account: "marketConfig";
-							},
-							{
-								kind: "account";
-								path: "platformConfig";
-							}
-						];
-					};
-				},
-				{
-					name: "marketState";
-					writable: true;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								value: [
-									109,
-									97,
-									114,
-									107,
+									118,
 									101,
+									110,
 									116,
-									45,
-									115,
-									116,
+									95,
 									97,
+									117,
 									116,
-									101
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
 								];
-							},
-							{
-								kind: "account";
-								path: "marketConfig";
-							},
-							{
-								kind: "account";
-								path: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
+					name: "program";
 				}
 			];
 			args: [];
 		},
 		{
-			name: "claimReward";
-			discriminator: [149, 95, 181, 242, 94, 90, 158, 162];
+			name: "amendResolution";
+			discriminator: [28, 127, 151, 177, 171, 104, 245, 46];
 			accounts: [
 				{
-					name: "user";
+					name: "resolver";
 					writable: true;
 					signer: true;
 				},
@@ -162,7 +124,7 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platform_config.admin";
+								path: "platform_config.platform_id";
 								account: "platformConfig";
 							}
 						];
@@ -197,7 +159,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -230,85 +193,65 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "marketVault";
-					writable: true;
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
 					pda: {
 						seeds: [
 							{
 								kind: "const";
 								value: [
-									109,
-									97,
-									114,
-									107,
+									95,
+									95,
 									101,
-									116,
-									45,
 									118,
+									101,
+									110,
+									116,
+									95,
 									97,
 									117,
-									108,
-									116
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
 								];
-							},
-							{
-								kind: "account";
-								path: "marketConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "position";
-					writable: true;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								value: [112, 111, 115, 105, 116, 105, 111, 110];
-							},
-							{
-								kind: "account";
-								path: "position.index";
-								account: "position";
-							},
-							{
-								kind: "account";
-								path: "user";
-							},
-							{
-								kind: "account";
-								path: "marketConfig";
-							}
-						];
-					};
-				},
+					name: "program";
+				}
+			];
+			args: [
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
+					name: "resolution";
+					type: "i64";
 				}
 			];
-			args: [];
 		},
 		{
-			name: "dismissMarket";
-			discriminator: [138, 225, 164, 155, 53, 68, 6, 26];
+			name: "approveMarket";
+			discriminator: [195, 83, 73, 224, 150, 237, 150, 5];
 			accounts: [
 				{
-					name: "admin";
+					name: "approver";
 					writable: true;
 					signer: true;
 				},
-				{
-					name: "creator";
-					writable: true;
-				},
 				{
 					name: "platformConfig";
 					pda: {
@@ -335,7 +278,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "admin";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -404,7 +348,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -437,7 +382,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -474,110 +420,59 @@ export type CurneyMarkets = {
 				{
 					name: "systemProgram";
 					address: "11111111111111111111111111111111";
-				}
-			];
-			args: [];
-		},
-		{
-			name: "initializePlatform";
-			discriminator: [119, 201, 101, 45, 75, 122, 89, 3];
-			accounts: [
-				{
-					name: "admin";
-					writable: true;
-					signer: true;
 				},
 				{
-					name: "platformConfig";
-					writable: true;
+					name: "eventAuthority";
 					pda: {
 						seeds: [
 							{
 								kind: "const";
 								value: [
-									112,
-									108,
-									97,
-									116,
-									102,
-									111,
-									114,
-									109,
-									45,
-									99,
-									111,
+									95,
+									95,
+									101,
+									118,
+									101,
 									110,
-									102,
-									105,
-									103
-								];
-							},
-							{
-								kind: "account";
-								path: "admin";
-							}
-						];
-					};
-				},
-				{
-					name: "platformTreasury";
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								value: [
-									112,
-									108,
+									116,
+									95,
 									97,
+									117,
 									116,
-									102,
+									104,
 									111,
 									114,
-									109,
-									45,
+									105,
 									116,
-									114,
-									101,
-									97,
-									115,
-									117,
-									114,
 									121
 								];
-							},
-							{
-								kind: "account";
-								path: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
+					name: "program";
 				}
 			];
 			args: [
 				{
-					name: "creatorFeeBps";
-					type: "u16";
+					name: "resolver";
+					type: {
+						option: "pubkey";
+					};
 				},
 				{
-					name: "platformFeeBps";
+					name: "revision";
 					type: "u16";
-				},
-				{
-					name: "marketProposalFee";
-					type: "u64";
 				}
 			];
 		},
 		{
-			name: "placePrediction";
-			discriminator: [79, 46, 195, 197, 50, 91, 88, 229];
+			name: "challengeResolution";
+			discriminator: [5, 230, 48, 100, 46, 252, 35, 119];
 			accounts: [
 				{
-					name: "user";
+					name: "challenger";
 					writable: true;
 					signer: true;
 				},
@@ -607,66 +502,32 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platform_config.admin";
+								path: "platform_config.platform_id";
 								account: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "platformTreasury";
-					writable: true;
+					name: "marketConfig";
 					pda: {
 						seeds: [
 							{
 								kind: "const";
 								value: [
-									112,
-									108,
-									97,
-									116,
-									102,
-									111,
-									114,
 									109,
-									45,
-									116,
-									114,
-									101,
 									97,
-									115,
-									117,
 									114,
-									121
-								];
-							},
-							{
-								kind: "account";
-								path: "platformConfig";
-							}
-						];
-					};
-				},
-				{
-					name: "marketConfig";
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								value: [
-									109,
-									97,
-									114,
-									107,
-									101,
-									116,
-									45,
-									99,
-									111,
-									110,
-									102,
-									105,
-									103
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
 								];
 							},
 							{
@@ -676,7 +537,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -709,7 +571,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -744,52 +607,55 @@ export type CurneyMarkets = {
 					};
 				},
 				{
-					name: "position";
-					writable: true;
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
 					pda: {
 						seeds: [
 							{
 								kind: "const";
-								value: [112, 111, 115, 105, 116, 105, 111, 110];
-							},
-							{
-								kind: "account";
-								path: "market_state.total_positions";
-								account: "marketState";
-							},
-							{
-								kind: "account";
-								path: "user";
-							},
-							{
-								kind: "account";
-								path: "marketConfig";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
 							}
 						];
 					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
+					name: "program";
 				}
 			];
 			args: [
 				{
-					name: "prediction";
+					name: "resolution";
 					type: "i64";
-				},
-				{
-					name: "stakeAmount";
-					type: "u64";
 				}
 			];
 		},
 		{
-			name: "proposeMarket";
-			discriminator: [39, 201, 255, 2, 194, 181, 58, 105];
+			name: "claimReward";
+			discriminator: [149, 95, 181, 242, 94, 90, 158, 162];
 			accounts: [
 				{
-					name: "creator";
+					name: "user";
 					writable: true;
 					signer: true;
 				},
@@ -819,49 +685,14 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platform_config.admin";
+								path: "platform_config.platform_id";
 								account: "platformConfig";
 							}
 						];
 					};
 				},
-				{
-					name: "platformTreasury";
-					writable: true;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								value: [
-									112,
-									108,
-									97,
-									116,
-									102,
-									111,
-									114,
-									109,
-									45,
-									116,
-									114,
-									101,
-									97,
-									115,
-									117,
-									114,
-									121
-								];
-							},
-							{
-								kind: "account";
-								path: "platformConfig";
-							}
-						];
-					};
-				},
 				{
 					name: "marketConfig";
-					writable: true;
 					pda: {
 						seeds: [
 							{
@@ -883,12 +714,14 @@ export type CurneyMarkets = {
 								];
 							},
 							{
-								kind: "arg";
-								path: "marketId";
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -921,13 +754,15 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
 				},
 				{
 					name: "marketVault";
+					writable: true;
 					pda: {
 						seeds: [
 							{
@@ -955,43 +790,93 @@ export type CurneyMarkets = {
 					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
-				}
-			];
-			args: [
+					name: "position";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [112, 111, 115, 105, 116, 105, 111, 110];
+							},
+							{
+								kind: "account";
+								path: "position.index";
+								account: "position";
+							},
+							{
+								kind: "account";
+								path: "user";
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
 				{
-					name: "marketId";
-					type: "u64";
+					name: "stakeMint";
+					optional: true;
 				},
 				{
-					name: "startTime";
-					type: "i64";
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
 				},
 				{
-					name: "endTime";
-					type: "i64";
+					name: "userTokenAccount";
+					writable: true;
+					optional: true;
 				},
 				{
-					name: "minPredictionPrice";
-					type: "u64";
+					name: "tokenProgram";
+					optional: true;
 				},
 				{
-					name: "question";
-					type: "string";
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
 				},
 				{
-					name: "description";
-					type: "string";
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
 				}
 			];
+			args: [];
 		},
 		{
-			name: "resolveMarket";
-			discriminator: [155, 23, 80, 173, 46, 74, 23, 239];
+			name: "claimSettledReward";
+			discriminator: [150, 131, 95, 16, 176, 141, 146, 173];
 			accounts: [
 				{
-					name: "admin";
+					name: "user";
 					writable: true;
 					signer: true;
 				},
@@ -1021,7 +906,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "admin";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -1055,7 +941,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -1088,184 +975,147 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
-				}
-			];
-			args: [
-				{
-					name: "resolution";
-					type: "i64";
-				},
-				{
-					name: "totalScores";
-					type: "u128";
-				}
-			];
-		},
-		{
-			name: "updateMarketConfig";
-			discriminator: [91, 87, 149, 101, 110, 116, 16, 120];
-			accounts: [
-				{
-					name: "admin";
+					name: "marketVault";
 					writable: true;
-					signer: true;
-				},
-				{
-					name: "platformConfig";
 					pda: {
 						seeds: [
 							{
 								kind: "const";
 								value: [
-									112,
-									108,
+									109,
 									97,
-									116,
-									102,
-									111,
 									114,
-									109,
+									107,
+									101,
+									116,
 									45,
-									99,
-									111,
-									110,
-									102,
-									105,
-									103
+									118,
+									97,
+									117,
+									108,
+									116
 								];
 							},
 							{
 								kind: "account";
-								path: "admin";
+								path: "marketConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "marketConfig";
+					name: "position";
 					writable: true;
 					pda: {
 						seeds: [
 							{
 								kind: "const";
-								value: [
-									109,
-									97,
-									114,
-									107,
-									101,
-									116,
-									45,
-									99,
-									111,
-									110,
-									102,
-									105,
-									103
-								];
+								value: [112, 111, 115, 105, 116, 105, 111, 110];
 							},
 							{
 								kind: "account";
-								path: "market_config.market_id";
-								account: "marketConfig";
+								path: "position.index";
+								account: "position";
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "user";
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "marketState";
+					name: "stakeMint";
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "userTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
 					pda: {
 						seeds: [
 							{
 								kind: "const";
 								value: [
-									109,
-									97,
-									114,
-									107,
+									95,
+									95,
 									101,
+									118,
+									101,
+									110,
 									116,
-									45,
-									115,
-									116,
+									95,
 									97,
+									117,
 									116,
-									101
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
 								];
-							},
-							{
-								kind: "account";
-								path: "marketConfig";
-							},
-							{
-								kind: "account";
-								path: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
+					name: "program";
 				}
 			];
 			args: [
 				{
-					name: "startTime";
-					type: {
-						option: "i64";
-					};
-				},
-				{
-					name: "endTime";
-					type: {
-						option: "i64";
-					};
-				},
-				{
-					name: "minPredictionPrice";
-					type: {
-						option: "u64";
-					};
-				},
-				{
-					name: "question";
-					type: {
-						option: "string";
-					};
+					name: "reward";
+					type: "u64";
 				},
 				{
-					name: "description";
+					name: "proof";
 					type: {
-						option: "string";
+						vec: {
+							array: ["u8", 32];
+						};
 					};
 				}
 			];
 		},
 		{
-			name: "updatePlatformConfig";
-			discriminator: [195, 60, 76, 129, 146, 45, 67, 143];
+			name: "collectPlatformFees";
+			discriminator: [191, 153, 219, 164, 5, 65, 153, 48];
 			accounts: [
 				{
-					name: "admin";
+					name: "treasurer";
 					writable: true;
 					signer: true;
 				},
 				{
 					name: "platformConfig";
-					writable: true;
 					pda: {
 						seeds: [
 							{
@@ -1290,48 +1140,15 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "admin";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
 							}
 						];
 					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
-				}
-			];
-			args: [
-				{
-					name: "creatorFeeBps";
-					type: {
-						option: "u16";
-					};
-				},
-				{
-					name: "platformFeeBps";
-					type: {
-						option: "u16";
-					};
-				},
-				{
-					name: "marketProposalFee";
-					type: {
-						option: "u64";
-					};
-				}
-			];
-		},
-		{
-			name: "withdrawCreatorRevenue";
-			discriminator: [17, 179, 212, 249, 155, 191, 74, 159];
-			accounts: [
-				{
-					name: "creator";
+					name: "platformTreasury";
 					writable: true;
-					signer: true;
-				},
-				{
-					name: "platformConfig";
 					pda: {
 						seeds: [
 							{
@@ -1346,18 +1163,19 @@ export type CurneyMarkets = {
 									114,
 									109,
 									45,
-									99,
-									111,
-									110,
-									102,
-									105,
-									103
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
 								];
 							},
 							{
 								kind: "account";
-								path: "platform_config.admin";
-								account: "platformConfig";
+								path: "platformConfig";
 							}
 						];
 					};
@@ -1391,7 +1209,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -1424,7 +1243,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "platformConfig";
+								path: "platform_config.namespace";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -1459,25 +1279,75 @@ export type CurneyMarkets = {
 					};
 				},
 				{
-					name: "rent";
-					address: "SysvarRent111111111111111111111111111111111";
+					name: "stakeMint";
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "treasuryTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
 				},
 				{
 					name: "systemProgram";
 					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
 				}
 			];
 			args: [];
 		},
 		{
-			name: "withdrawPlatformFees";
-			discriminator: [87, 24, 138, 122, 62, 146, 186, 199];
+			name: "dismissMarket";
+			discriminator: [138, 225, 164, 155, 53, 68, 6, 26];
 			accounts: [
 				{
-					name: "admin";
+					name: "approver";
 					writable: true;
 					signer: true;
 				},
+				{
+					name: "creator";
+					writable: true;
+				},
 				{
 					name: "platformConfig";
 					pda: {
@@ -1504,7 +1374,8 @@ export type CurneyMarkets = {
 							},
 							{
 								kind: "account";
-								path: "admin";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
 							}
 						];
 					};
@@ -1544,98 +1415,7503 @@ export type CurneyMarkets = {
 					};
 				},
 				{
-					name: "rent";
-					address: "SysvarRent111111111111111111111111111111111";
+					name: "marketConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
 				},
 				{
-					name: "systemProgram";
-					address: "11111111111111111111111111111111";
-				}
-			];
-			args: [];
-		}
-	];
-	accounts: [
-		{
-			name: "marketConfig";
-			discriminator: [119, 255, 200, 88, 252, 82, 128, 24];
-		},
-		{
-			name: "marketState";
-			discriminator: [0, 125, 123, 215, 95, 96, 164, 194];
-		},
-		{
-			name: "platformConfig";
-			discriminator: [160, 78, 128, 0, 248, 83, 230, 160];
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketTombstone";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									116,
+									111,
+									109,
+									98,
+									115,
+									116,
+									111,
+									110,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "stakeMint";
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "creatorTokenAccount";
+					docs: [
+						"Receives any tokens sent to the vault, only required when it holds a balance"
+					];
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "reason";
+					type: {
+						defined: {
+							name: "dismissReason";
+						};
+					};
+				}
+			];
+		},
+		{
+			name: "disputeResolution";
+			discriminator: [89, 169, 106, 71, 131, 77, 122, 232];
+			accounts: [
+				{
+					name: "challenger";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "dispute";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [100, 105, 115, 112, 117, 116, 101];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "challenger";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "proposedResolution";
+					type: "i64";
+				}
+			];
+		},
+		{
+			name: "expireMarket";
+			discriminator: [213, 191, 249, 51, 63, 168, 186, 180];
+			accounts: [
+				{
+					name: "signer";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "finalizeResolution";
+			discriminator: [191, 74, 94, 214, 45, 150, 152, 125];
+			accounts: [
+				{
+					name: "signer";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "creator";
+					writable: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "grantRole";
+			discriminator: [218, 234, 128, 15, 82, 33, 236, 253];
+			accounts: [
+				{
+					name: "admin";
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "role";
+					type: {
+						defined: {
+							name: "platformRole";
+						};
+					};
+				},
+				{
+					name: "holder";
+					type: "pubkey";
+				}
+			];
+		},
+		{
+			name: "initializePlatform";
+			discriminator: [119, 201, 101, 45, 75, 122, 89, 3];
+			accounts: [
+				{
+					name: "admin";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "arg";
+								path: "platformId";
+							}
+						];
+					};
+				},
+				{
+					name: "platformTreasury";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
+								];
+							},
+							{
+								kind: "account";
+								path: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "platformId";
+					type: "u64";
+				},
+				{
+					name: "creatorFeeBps";
+					type: "u16";
+				},
+				{
+					name: "platformFeeBps";
+					type: "u16";
+				},
+				{
+					name: "marketProposalFee";
+					type: "u64";
+				},
+				{
+					name: "dismissalRefundBps";
+					type: "u16";
+				},
+				{
+					name: "spamDismissalRefundBps";
+					type: "u16";
+				},
+				{
+					name: "withdrawalRefundBps";
+					type: "u16";
+				},
+				{
+					name: "disputePeriod";
+					type: "i64";
+				},
+				{
+					name: "disputeBond";
+					type: "u64";
+				},
+				{
+					name: "disputeRewardBps";
+					type: "u16";
+				},
+				{
+					name: "resolverGracePeriod";
+					type: "i64";
+				},
+				{
+					name: "settlementGracePeriod";
+					type: "i64";
+				}
+			];
+		},
+		{
+			name: "initializePriceSamples";
+			discriminator: [76, 198, 96, 234, 41, 147, 240, 185];
+			accounts: [
+				{
+					name: "payer";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "priceSamples";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									114,
+									105,
+									99,
+									101,
+									45,
+									115,
+									97,
+									109,
+									112,
+									108,
+									101,
+									115
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "initializeResolverCommittee";
+			discriminator: [234, 216, 199, 133, 165, 17, 241, 219];
+			accounts: [
+				{
+					name: "admin";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "resolverCommittee";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									114,
+									101,
+									115,
+									111,
+									108,
+									118,
+									101,
+									114,
+									45,
+									99,
+									111,
+									109,
+									109,
+									105,
+									116,
+									116,
+									101,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "members";
+					type: {
+						vec: "pubkey";
+					};
+				},
+				{
+					name: "threshold";
+					type: "u8";
+				},
+				{
+					name: "tolerance";
+					type: "u64";
+				}
+			];
+		},
+		{
+			name: "migratePlatform";
+			discriminator: [199, 188, 136, 113, 2, 148, 42, 14];
+			accounts: [
+				{
+					name: "admin";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "legacyPlatformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "admin";
+							}
+						];
+					};
+				},
+				{
+					name: "legacyPlatformTreasury";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
+								];
+							},
+							{
+								kind: "account";
+								path: "legacyPlatformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "platformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "arg";
+								path: "platformId";
+							}
+						];
+					};
+				},
+				{
+					name: "platformTreasury";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
+								];
+							},
+							{
+								kind: "account";
+								path: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "platformId";
+					type: "u64";
+				}
+			];
+		},
+		{
+			name: "placePrediction";
+			discriminator: [79, 46, 195, 197, 50, 91, 88, 229];
+			accounts: [
+				{
+					name: "user";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "position";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [112, 111, 115, 105, 116, 105, 111, 110];
+							},
+							{
+								kind: "account";
+								path: "market_state.total_positions";
+								account: "marketState";
+							},
+							{
+								kind: "account";
+								path: "user";
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "stakeMint";
+					optional: true;
+				},
+				{
+					name: "userTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "prediction";
+					type: "i64";
+				},
+				{
+					name: "stakeAmount";
+					type: "u64";
+				}
+			];
+		},
+		{
+			name: "postSettlementRoot";
+			discriminator: [204, 137, 70, 196, 159, 4, 179, 1];
+			accounts: [
+				{
+					name: "resolver";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "root";
+					type: {
+						array: ["u8", 32];
+					};
+				},
+				{
+					name: "totalPayout";
+					type: "u64";
+				}
+			];
+		},
+		{
+			name: "proposeAdmin";
+			discriminator: [121, 214, 199, 212, 87, 39, 117, 234];
+			accounts: [
+				{
+					name: "admin";
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "pendingAdmin";
+					type: {
+						option: "pubkey";
+					};
+				}
+			];
+		},
+		{
+			name: "proposeMarket";
+			discriminator: [39, 201, 255, 2, 194, 181, 58, 105];
+			accounts: [
+				{
+					name: "creator";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "arg";
+								path: "marketId";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketTombstone";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									116,
+									111,
+									109,
+									98,
+									115,
+									116,
+									111,
+									110,
+									101
+								];
+							},
+							{
+								kind: "arg";
+								path: "marketId";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "stakeMint";
+					docs: [
+						"Mint the market is denominated in, or `None` for a SOL market"
+					];
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: "account";
+								path: "marketVault";
+							},
+							{
+								kind: "account";
+								path: "tokenProgram";
+							},
+							{
+								kind: "account";
+								path: "stakeMint";
+							}
+						];
+						program: {
+							kind: "const";
+							value: [
+								140,
+								151,
+								37,
+								143,
+								78,
+								36,
+								137,
+								241,
+								187,
+								61,
+								16,
+								41,
+								20,
+								142,
+								13,
+								131,
+								11,
+								90,
+								19,
+								153,
+								218,
+								255,
+								16,
+								132,
+								4,
+								142,
+								123,
+								216,
+								219,
+								233,
+								248,
+								89
+							];
+						};
+					};
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "associatedTokenProgram";
+					optional: true;
+					address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "marketId";
+					type: "u64";
+				},
+				{
+					name: "startTime";
+					type: "i64";
+				},
+				{
+					name: "endTime";
+					type: "i64";
+				},
+				{
+					name: "resolutionDeadline";
+					type: "i64";
+				},
+				{
+					name: "minPredictionPrice";
+					type: "u64";
+				},
+				{
+					name: "sigmaMin";
+					type: "u64";
+				},
+				{
+					name: "sigmaMax";
+					type: "u64";
+				},
+				{
+					name: "stakeWeighting";
+					type: {
+						defined: {
+							name: "stakeWeighting";
+						};
+					};
+				},
+				{
+					name: "scoringCurve";
+					type: {
+						defined: {
+							name: "scoringCurve";
+						};
+					};
+				},
+				{
+					name: "settlementMode";
+					type: {
+						defined: {
+							name: "settlementMode";
+						};
+					};
+				},
+				{
+					name: "oracle";
+					type: {
+						option: {
+							defined: {
+								name: "priceOracle";
+							};
+						};
+					};
+				},
+				{
+					name: "creatorBond";
+					type: "u64";
+				},
+				{
+					name: "question";
+					type: "string";
+				},
+				{
+					name: "description";
+					type: "string";
+				}
+			];
+		},
+		{
+			name: "proposeResolution";
+			discriminator: [19, 68, 181, 23, 194, 146, 152, 252];
+			accounts: [
+				{
+					name: "creator";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "resolution";
+					type: "i64";
+				}
+			];
+		},
+		{
+			name: "pushPriceSample";
+			discriminator: [159, 115, 88, 79, 135, 129, 51, 130];
+			accounts: [
+				{
+					name: "keeper";
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "priceSamples";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									114,
+									105,
+									99,
+									101,
+									45,
+									115,
+									97,
+									109,
+									112,
+									108,
+									101,
+									115
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "priceUpdate";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "refundPosition";
+			discriminator: [92, 200, 157, 98, 87, 187, 115, 172];
+			accounts: [
+				{
+					name: "user";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "position";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [112, 111, 115, 105, 116, 105, 111, 110];
+							},
+							{
+								kind: "account";
+								path: "position.index";
+								account: "position";
+							},
+							{
+								kind: "account";
+								path: "user";
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "stakeMint";
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "userTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "resolveFromOracle";
+			discriminator: [150, 201, 104, 215, 112, 4, 247, 38];
+			accounts: [
+				{
+					name: "signer";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "priceUpdate";
+				},
+				{
+					name: "priceSamples";
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									114,
+									105,
+									99,
+									101,
+									45,
+									115,
+									97,
+									109,
+									112,
+									108,
+									101,
+									115
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "resolveMarket";
+			discriminator: [155, 23, 80, 173, 46, 74, 23, 239];
+			accounts: [
+				{
+					name: "resolver";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "resolverCommittee";
+					docs: [
+						"Only needed for committee attestations, so platforms without a committee can still",
+						"resolve through the designated and platform resolvers"
+					];
+					writable: true;
+					optional: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									114,
+									101,
+									115,
+									111,
+									108,
+									118,
+									101,
+									114,
+									45,
+									99,
+									111,
+									109,
+									109,
+									105,
+									116,
+									116,
+									101,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "resolution";
+					type: "i64";
+				}
+			];
+		},
+		{
+			name: "returnBonds";
+			discriminator: [153, 252, 204, 82, 192, 40, 31, 210];
+			accounts: [
+				{
+					name: "signer";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "creator";
+					writable: true;
+				},
+				{
+					name: "challenger";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "returnDisputeBond";
+			discriminator: [62, 8, 211, 92, 179, 70, 219, 192];
+			accounts: [
+				{
+					name: "signer";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "challenger";
+					writable: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "dispute";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [100, 105, 115, 112, 117, 116, 101];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "dispute.challenger";
+								account: "dispute";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "revokeRole";
+			discriminator: [179, 232, 2, 180, 48, 227, 82, 7];
+			accounts: [
+				{
+					name: "admin";
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "role";
+					type: {
+						defined: {
+							name: "platformRole";
+						};
+					};
+				}
+			];
+		},
+		{
+			name: "setPaused";
+			discriminator: [91, 60, 125, 192, 176, 225, 166, 218];
+			accounts: [
+				{
+					name: "pauser";
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "isPaused";
+					type: "bool";
+				}
+			];
+		},
+		{
+			name: "settleDispute";
+			discriminator: [155, 147, 5, 44, 20, 204, 146, 43];
+			accounts: [
+				{
+					name: "arbiter";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "challenger";
+					writable: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "platformTreasury";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
+								];
+							},
+							{
+								kind: "account";
+								path: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "dispute";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [100, 105, 115, 112, 117, 116, 101];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "dispute.challenger";
+								account: "dispute";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "overturn";
+					type: "bool";
+				}
+			];
+		},
+		{
+			name: "settlePositions";
+			discriminator: [43, 66, 200, 216, 219, 186, 44, 87];
+			accounts: [
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "settleResolutionChallenge";
+			discriminator: [156, 125, 227, 213, 101, 124, 249, 108];
+			accounts: [
+				{
+					name: "arbiter";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "creator";
+					writable: true;
+				},
+				{
+					name: "challenger";
+					writable: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "platformTreasury";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
+								];
+							},
+							{
+								kind: "account";
+								path: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "challengeSucceeds";
+					type: "bool";
+				}
+			];
+		},
+		{
+			name: "updateMarketConfig";
+			discriminator: [91, 87, 149, 101, 110, 116, 16, 120];
+			accounts: [
+				{
+					name: "authority";
+					docs: [
+						"The platform approver, or the market creator for the fields they may edit"
+					];
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "startTime";
+					type: {
+						option: "i64";
+					};
+				},
+				{
+					name: "endTime";
+					type: {
+						option: "i64";
+					};
+				},
+				{
+					name: "resolutionDeadline";
+					type: {
+						option: "i64";
+					};
+				},
+				{
+					name: "minPredictionPrice";
+					type: {
+						option: "u64";
+					};
+				},
+				{
+					name: "sigmaMin";
+					type: {
+						option: "u64";
+					};
+				},
+				{
+					name: "sigmaMax";
+					type: {
+						option: "u64";
+					};
+				},
+				{
+					name: "stakeWeighting";
+					type: {
+						option: {
+							defined: {
+								name: "stakeWeighting";
+							};
+						};
+					};
+				},
+				{
+					name: "scoringCurve";
+					type: {
+						option: {
+							defined: {
+								name: "scoringCurve";
+							};
+						};
+					};
+				},
+				{
+					name: "settlementMode";
+					type: {
+						option: {
+							defined: {
+								name: "settlementMode";
+							};
+						};
+					};
+				},
+				{
+					name: "question";
+					type: {
+						option: "string";
+					};
+				},
+				{
+					name: "description";
+					type: {
+						option: "string";
+					};
+				}
+			];
+		},
+		{
+			name: "updatePlatformConfig";
+			discriminator: [195, 60, 76, 129, 146, 45, 67, 143];
+			accounts: [
+				{
+					name: "authority";
+					docs: ["The treasurer for the fee settings, the admin for the rest"];
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "creatorFeeBps";
+					type: {
+						option: "u16";
+					};
+				},
+				{
+					name: "platformFeeBps";
+					type: {
+						option: "u16";
+					};
+				},
+				{
+					name: "marketProposalFee";
+					type: {
+						option: "u64";
+					};
+				},
+				{
+					name: "dismissalRefundBps";
+					type: {
+						option: "u16";
+					};
+				},
+				{
+					name: "spamDismissalRefundBps";
+					type: {
+						option: "u16";
+					};
+				},
+				{
+					name: "withdrawalRefundBps";
+					type: {
+						option: "u16";
+					};
+				},
+				{
+					name: "disputePeriod";
+					type: {
+						option: "i64";
+					};
+				},
+				{
+					name: "disputeBond";
+					type: {
+						option: "u64";
+					};
+				},
+				{
+					name: "disputeRewardBps";
+					type: {
+						option: "u16";
+					};
+				},
+				{
+					name: "resolverGracePeriod";
+					type: {
+						option: "i64";
+					};
+				},
+				{
+					name: "settlementGracePeriod";
+					type: {
+						option: "i64";
+					};
+				}
+			];
+		},
+		{
+			name: "updateResolverCommittee";
+			discriminator: [18, 45, 65, 141, 174, 176, 184, 189];
+			accounts: [
+				{
+					name: "admin";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "resolverCommittee";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									114,
+									101,
+									115,
+									111,
+									108,
+									118,
+									101,
+									114,
+									45,
+									99,
+									111,
+									109,
+									109,
+									105,
+									116,
+									116,
+									101,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "members";
+					type: {
+						option: {
+							vec: "pubkey";
+						};
+					};
+				},
+				{
+					name: "threshold";
+					type: {
+						option: "u8";
+					};
+				},
+				{
+					name: "tolerance";
+					type: {
+						option: "u64";
+					};
+				}
+			];
+		},
+		{
+			name: "voidMarket";
+			discriminator: [243, 175, 46, 124, 95, 101, 39, 69];
+			accounts: [
+				{
+					name: "admin";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [
+				{
+					name: "refundPolicy";
+					type: {
+						defined: {
+							name: "feeRefundPolicy";
+						};
+					};
+				}
+			];
+		},
+		{
+			name: "withdrawCreatorRevenue";
+			discriminator: [17, 179, 212, 249, 155, 191, 74, 159];
+			accounts: [
+				{
+					name: "creator";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "stakeMint";
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "creatorTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "rent";
+					address: "SysvarRent111111111111111111111111111111111";
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "withdrawPlatformFees";
+			discriminator: [87, 24, 138, 122, 62, 146, 186, 199];
+			accounts: [
+				{
+					name: "treasurer";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "platformTreasury";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
+								];
+							},
+							{
+								kind: "account";
+								path: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "stakeMint";
+					docs: [
+						"Mint whose treasury balance to withdraw, or `None` to withdraw SOL"
+					];
+					optional: true;
+				},
+				{
+					name: "treasuryTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "treasurerTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "rent";
+					address: "SysvarRent111111111111111111111111111111111";
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		},
+		{
+			name: "withdrawProposal";
+			discriminator: [135, 1, 185, 99, 36, 69, 15, 38];
+			accounts: [
+				{
+					name: "creator";
+					writable: true;
+					signer: true;
+				},
+				{
+					name: "platformConfig";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "platform_config.platform_id";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "platformTreasury";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									112,
+									108,
+									97,
+									116,
+									102,
+									111,
+									114,
+									109,
+									45,
+									116,
+									114,
+									101,
+									97,
+									115,
+									117,
+									114,
+									121
+								];
+							},
+							{
+								kind: "account";
+								path: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketConfig";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									99,
+									111,
+									110,
+									102,
+									105,
+									103
+								];
+							},
+							{
+								kind: "account";
+								path: "market_config.market_id";
+								account: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketState";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									115,
+									116,
+									97,
+									116,
+									101
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							},
+							{
+								kind: "account";
+								path: "platform_config.namespace";
+								account: "platformConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "marketVault";
+					writable: true;
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									109,
+									97,
+									114,
+									107,
+									101,
+									116,
+									45,
+									118,
+									97,
+									117,
+									108,
+									116
+								];
+							},
+							{
+								kind: "account";
+								path: "marketConfig";
+							}
+						];
+					};
+				},
+				{
+					name: "stakeMint";
+					optional: true;
+				},
+				{
+					name: "vaultTokenAccount";
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "creatorTokenAccount";
+					docs: [
+						"Receives any tokens sent to the vault, only required when it holds a balance"
+					];
+					writable: true;
+					optional: true;
+				},
+				{
+					name: "tokenProgram";
+					optional: true;
+				},
+				{
+					name: "systemProgram";
+					address: "11111111111111111111111111111111";
+				},
+				{
+					name: "eventAuthority";
+					pda: {
+						seeds: [
+							{
+								kind: "const";
+								value: [
+									95,
+									95,
+									101,
+									118,
+									101,
+									110,
+									116,
+									95,
+									97,
+									117,
+									116,
+									104,
+									111,
+									114,
+									105,
+									116,
+									121
+								];
+							}
+						];
+					};
+				},
+				{
+					name: "program";
+				}
+			];
+			args: [];
+		}
+	];
+	accounts: [
+		{
+			name: "dispute";
+			discriminator: [36, 49, 241, 67, 40, 36, 241, 74];
+		},
+		{
+			name: "marketConfig";
+			discriminator: [119, 255, 200, 88, 252, 82, 128, 24];
+		},
+		{
+			name: "marketState";
+			discriminator: [0, 125, 123, 215, 95, 96, 164, 194];
+		},
+		{
+			name: "marketTombstone";
+			discriminator: [73, 197, 115, 18, 66, 80, 41, 116];
+		},
+		{
+			name: "platformConfig";
+			discriminator: [160, 78, 128, 0, 248, 83, 230, 160];
+		},
+		{
+			name: "position";
+			discriminator: [170, 188, 143, 228, 122, 64, 247, 208];
+		},
+		{
+			name: "priceSamples";
+			discriminator: [148, 103, 129, 150, 85, 111, 196, 95];
+		},
+		{
+			name: "resolverCommittee";
+			discriminator: [185, 42, 7, 17, 44, 136, 226, 207];
+		}
+	];
+	events: [
+		{
+			discriminator: [129, 249, 226, 227, 199, 82, 110, 243];
+			name: "adminProposed";
+		},
+		{
+			discriminator: [255, 147, 182, 5, 199, 217, 38, 179];
+			name: "adminTransferred";
+		},
+		{
+			discriminator: [177, 213, 117, 225, 166, 11, 54, 218];
+			name: "attestationSubmitted";
+		},
+		{
+			discriminator: [88, 165, 42, 147, 159, 252, 0, 229];
+			name: "bondsReturned";
+		},
+		{
+			discriminator: [28, 4, 195, 59, 183, 122, 32, 126];
+			name: "creatorRevenueWithdrawn";
+		},
+		{
+			discriminator: [171, 172, 244, 87, 95, 65, 226, 59];
+			name: "disputeBondReturned";
+		},
+		{
+			discriminator: [239, 222, 102, 235, 193, 85, 1, 214];
+			name: "disputeOpened";
+		},
+		{
+			discriminator: [254, 31, 147, 164, 50, 13, 223, 158];
+			name: "disputeSettled";
+		},
+		{
+			discriminator: [213, 222, 68, 97, 16, 1, 31, 38];
+			name: "marketApproved";
+		},
+		{
+			discriminator: [38, 151, 70, 216, 174, 16, 118, 150];
+			name: "marketDismissed";
+		},
+		{
+			discriminator: [241, 200, 151, 120, 229, 7, 131, 124];
+			name: "marketProposed";
+		},
+		{
+			discriminator: [89, 67, 230, 95, 143, 106, 199, 202];
+			name: "marketResolved";
+		},
+		{
+			discriminator: [170, 51, 74, 147, 116, 168, 217, 251];
+			name: "marketUpdated";
+		},
+		{
+			discriminator: [217, 12, 138, 39, 108, 75, 89, 26];
+			name: "marketVoided";
+		},
+		{
+			discriminator: [198, 206, 187, 204, 148, 251, 237, 25];
+			name: "platformConfigUpdated";
+		},
+		{
+			discriminator: [109, 143, 129, 146, 174, 164, 57, 226];
+			name: "platformFeesCollected";
+		},
+		{
+			discriminator: [21, 216, 172, 170, 221, 168, 147, 193];
+			name: "platformFeesWithdrawn";
+		},
+		{
+			discriminator: [16, 222, 212, 5, 213, 140, 112, 162];
+			name: "platformInitialized";
+		},
+		{
+			discriminator: [13, 51, 71, 173, 122, 237, 176, 201];
+			name: "platformMigrated";
+		},
+		{
+			discriminator: [49, 75, 8, 185, 116, 121, 28, 119];
+			name: "platformPauseSet";
+		},
+		{
+			discriminator: [130, 211, 170, 182, 121, 164, 58, 209];
+			name: "positionsSettled";
+		},
+		{
+			discriminator: [230, 40, 242, 26, 50, 92, 158, 221];
+			name: "predictionPlaced";
+		},
+		{
+			discriminator: [173, 221, 182, 109, 27, 181, 246, 231];
+			name: "priceSampleRecorded";
+		},
+		{
+			discriminator: [123, 72, 25, 163, 17, 100, 192, 195];
+			name: "priceSamplesInitialized";
+		},
+		{
+			discriminator: [118, 247, 117, 254, 124, 178, 46, 200];
+			name: "proposalWithdrawn";
+		},
+		{
+			discriminator: [99, 113, 224, 222, 3, 163, 2, 64];
+			name: "resolutionAmended";
+		},
+		{
+			discriminator: [30, 176, 243, 172, 141, 62, 59, 53];
+			name: "resolutionChallengeSettled";
+		},
+		{
+			discriminator: [134, 252, 105, 19, 175, 223, 45, 16];
+			name: "resolutionChallenged";
+		},
+		{
+			discriminator: [209, 21, 193, 193, 218, 234, 131, 108];
+			name: "resolutionProposed";
+		},
+		{
+			discriminator: [241, 50, 93, 198, 77, 163, 103, 161];
+			name: "resolverCommitteeUpdated";
+		},
+		{
+			discriminator: [49, 28, 87, 84, 158, 48, 229, 175];
+			name: "rewardClaimed";
+		},
+		{
+			discriminator: [220, 183, 89, 228, 143, 63, 246, 58];
+			name: "roleGranted";
+		},
+		{
+			discriminator: [167, 183, 52, 229, 126, 206, 62, 61];
+			name: "roleRevoked";
+		},
+		{
+			discriminator: [34, 230, 237, 82, 0, 122, 23, 168];
+			name: "settlementRootPosted";
+		},
+		{
+			discriminator: [81, 188, 224, 106, 3, 59, 199, 216];
+			name: "stakeRefunded";
+		}
+	];
+	errors: [
+		{
+			code: 6000;
+			name: "rewardAlreadyClaimed";
+			msg: "Position reward already claimed";
+		},
+		{
+			code: 6001;
+			name: "invalidSigma";
+			msg: "The sigma must be greater than zero";
+		},
+		{
+			code: 6002;
+			name: "positionAlreadySettled";
+			msg: "Position has already been settled";
+		},
+		{
+			code: 6003;
+			name: "positionNotSettled";
+			msg: "Position has not been settled yet";
+		},
+		{
+			code: 6004;
+			name: "invalidMarket";
+			msg: "Position does not belong to this market";
+		},
+		{
+			code: 6005;
+			name: "invalidProof";
+			msg: "Merkle proof does not match the settlement root";
+		},
+		{
+			code: 6006;
+			name: "stakeAlreadyRefunded";
+			msg: "Position stake already refunded";
+		}
+	];
+	types: [
+		{
+			name: "adminProposed";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
+					},
+					{
+						name: "admin";
+						type: "pubkey";
+					},
+					{
+						docs: ["`None` when a pending transfer is cancelled"];
+						name: "pendingAdmin";
+						type: {
+							option: "pubkey";
+						};
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "adminTransferred";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
+					},
+					{
+						name: "previousAdmin";
+						type: "pubkey";
+					},
+					{
+						name: "admin";
+						type: "pubkey";
+					},
+					{
+						docs: ["Roles the previous admin held, now held by the new admin"];
+						name: "reassignedRoles";
+						type: {
+							vec: {
+								defined: {
+									name: "platformRole";
+								};
+							};
+						};
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "attestation";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "member";
+						type: "pubkey";
+					},
+					{
+						name: "value";
+						type: "i64";
+					},
+					{
+						name: "isFlagged";
+						docs: [
+							"Set at resolution when the value is further than the tolerance from the median"
+						];
+						type: "bool";
+					}
+				];
+			};
+		},
+		{
+			name: "attestationSubmitted";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "member";
+						type: "pubkey";
+					},
+					{
+						name: "value";
+						type: "i64";
+					},
+					{
+						docs: ["Attestations from current members, including this one"];
+						name: "attestations";
+						type: "u8";
+					},
+					{
+						name: "threshold";
+						type: "u8";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "bondsReturned";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "creator";
+						type: "pubkey";
+					},
+					{
+						name: "creatorBond";
+						type: "u64";
+					},
+					{
+						name: "challenger";
+						type: {
+							option: "pubkey";
+						};
+					},
+					{
+						name: "challengerBond";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "creatorRevenueWithdrawn";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "creator";
+						type: "pubkey";
+					},
+					{
+						name: "amount";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "dismissReason";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "unclear";
+					},
+					{
+						name: "unresolvable";
+					},
+					{
+						name: "duplicate";
+					},
+					{
+						name: "inappropriate";
+					},
+					{
+						name: "spam";
+					}
+				];
+			};
+		},
+		{
+			name: "dispute";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "bump";
+						type: "u8";
+					},
+					{
+						name: "bond";
+						type: "u64";
+					},
+					{
+						name: "proposedResolution";
+						type: "i64";
+					},
+					{
+						name: "challenger";
+						type: "pubkey";
+					},
+					{
+						name: "market";
+						type: "pubkey";
+					}
+				];
+			};
+		},
+		{
+			name: "disputeBondReturned";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "dispute";
+						type: "pubkey";
+					},
+					{
+						name: "challenger";
+						type: "pubkey";
+					},
+					{
+						name: "bond";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "disputeOpened";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "dispute";
+						type: "pubkey";
+					},
+					{
+						name: "challenger";
+						type: "pubkey";
+					},
+					{
+						name: "proposedResolution";
+						type: "i64";
+					},
+					{
+						name: "bond";
+						type: "u64";
+					},
+					{
+						name: "openDisputes";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "disputeSettled";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "dispute";
+						type: "pubkey";
+					},
+					{
+						name: "arbiter";
+						type: "pubkey";
+					},
+					{
+						name: "challenger";
+						type: "pubkey";
+					},
+					{
+						name: "overturned";
+						type: "bool";
+					},
+					{
+						docs: ["Market resolution after the ruling"];
+						name: "resolution";
+						type: "i64";
+					},
+					{
+						docs: ["Reward paid from the treasury to a successful challenger"];
+						name: "reward";
+						type: "u64";
+					},
+					{
+						docs: [
+							"Bond slashed to the treasury when the resolution is upheld"
+						];
+						name: "bondSlashed";
+						type: "u64";
+					},
+					{
+						name: "openDisputes";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "feeRefundPolicy";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "stakeOnly";
+					},
+					{
+						name: "stakeAndCreatorFee";
+					},
+					{
+						name: "grossStake";
+					}
+				];
+			};
+		},
+		{
+			name: "marketApproved";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "revision";
+						type: "u16";
+					},
+					{
+						name: "resolver";
+						type: {
+							option: "pubkey";
+						};
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "marketConfig";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "bump";
+						type: "u8";
+					},
+					{
+						name: "vaultBump";
+						type: "u8";
+					},
+					{
+						name: "marketId";
+						type: "u64";
+					},
+					{
+						name: "startTime";
+						type: "i64";
+					},
+					{
+						name: "endTime";
+						type: "i64";
+					},
+					{
+						name: "resolutionDeadline";
+						type: "i64";
+					},
+					{
+						name: "minPredictionPrice";
+						type: "u64";
+					},
+					{
+						name: "sigmaMin";
+						type: "u64";
+					},
+					{
+						name: "sigmaMax";
+						type: "u64";
+					},
+					{
+						name: "stakeWeighting";
+						type: {
+							defined: {
+								name: "stakeWeighting";
+							};
+						};
+					},
+					{
+						name: "scoringCurve";
+						type: {
+							defined: {
+								name: "scoringCurve";
+							};
+						};
+					},
+					{
+						name: "settlementMode";
+						type: {
+							defined: {
+								name: "settlementMode";
+							};
+						};
+					},
+					{
+						name: "stakeMint";
+						type: {
+							option: "pubkey";
+						};
+					},
+					{
+						name: "oracle";
+						type: {
+							option: {
+								defined: {
+									name: "priceOracle";
+								};
+							};
+						};
+					},
+					{
+						name: "resolver";
+						type: {
+							option: "pubkey";
+						};
+					},
+					{
+						name: "creatorBond";
+						docs: [
+							"SOL bond the creator locks to resolve the market optimistically, or zero"
+						];
+						type: "u64";
+					},
+					{
+						name: "proposalFee";
+						docs: [
+							"Proposal fee the creator paid, escrowed in the market vault until approval or dismissal"
+						];
+						type: "u64";
+					},
+					{
+						name: "revision";
+						docs: [
+							"Number of edits made to the proposal, the admin approves a specific revision"
+						];
+						type: "u16";
+					},
+					{
+						name: "question";
+						type: "string";
+					},
+					{
+						name: "description";
+						type: "string";
+					},
+					{
+						name: "creator";
+						type: "pubkey";
+					},
+					{
+						name: "marketState";
+						type: "pubkey";
+					}
+				];
+			};
+		},
+		{
+			name: "marketDismissed";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "marketId";
+						type: "u64";
+					},
+					{
+						name: "creator";
+						type: "pubkey";
+					},
+					{
+						name: "reason";
+						type: {
+							defined: {
+								name: "dismissReason";
+							};
+						};
+					},
+					{
+						name: "proposalFeeRefund";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "marketProposed";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "marketId";
+						type: "u64";
+					},
+					{
+						name: "creator";
+						type: "pubkey";
+					},
+					{
+						name: "proposalFee";
+						type: "u64";
+					},
+					{
+						name: "creatorBond";
+						type: "u64";
+					},
+					{
+						name: "stakeMint";
+						type: {
+							option: "pubkey";
+						};
+					},
+					{
+						name: "startTime";
+						type: "i64";
+					},
+					{
+						name: "endTime";
+						type: "i64";
+					},
+					{
+						name: "resolutionDeadline";
+						type: "i64";
+					},
+					{
+						name: "minPredictionPrice";
+						type: "u64";
+					},
+					{
+						name: "sigmaMin";
+						type: "u64";
+					},
+					{
+						name: "sigmaMax";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "marketResolved";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "resolution";
+						type: "i64";
+					},
+					{
+						name: "disputeEndsAt";
+						type: "i64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "marketState";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "bump";
+						type: "u8";
+					},
+					{
+						name: "status";
+						docs: [
+							"Last recorded status, see `MarketState::status_at` for the current one"
+						];
+						type: {
+							defined: {
+								name: "marketStatus";
+							};
+						};
+					},
+					{
+						name: "isExpired";
+						type: "bool";
+					},
+					{
+						name: "hasClaims";
+						type: "bool";
+					},
+					{
+						name: "resolution";
+						type: {
+							option: "i64";
+						};
+					},
+					{
+						name: "resolutionAmendments";
+						type: "u16";
+					},
+					{
+						name: "refundPolicy";
+						type: {
+							option: {
+								defined: {
+									name: "feeRefundPolicy";
+								};
+							};
+						};
+					},
+					{
+						name: "disputeEndsAt";
+						type: "i64";
+					},
+					{
+						name: "openDisputes";
+						type: "u64";
+					},
+					{
+						name: "attestations";
+						type: {
+							vec: {
+								defined: {
+									name: "attestation";
+								};
+							};
+						};
+					},
+					{
+						name: "creatorBondLocked";
+						type: "bool";
+					},
+					{
+						name: "proposedResolution";
+						type: {
+							option: "i64";
+						};
+					},
+					{
+						name: "proposalEndsAt";
+						type: "i64";
+					},
+					{
+						name: "challenger";
+						type: {
+							option: "pubkey";
+						};
+					},
+					{
+						name: "challengedResolution";
+						type: {
+							option: "i64";
+						};
+					},
+					{
+						name: "challengerBond";
+						type: "u64";
+					},
+					{
+						name: "totalPool";
+						type: "u64";
+					},
+					{
+						name: "totalPositions";
+						type: "u64";
+					},
+					{
+						name: "scoredPositions";
+						type: "u64";
+					},
+					{
+						name: "totalScores";
+						type: "u128";
+					},
+					{
+						name: "settlementRoot";
+						type: {
+							option: {
+								array: ["u8", 32];
+							};
+						};
+					},
+					{
+						name: "settlementPayout";
+						type: "u64";
+					},
+					{
+						name: "claimedPayout";
+						type: "u64";
+					},
+					{
+						name: "creatorFeeRevenue";
+						type: "u64";
+					},
+					{
+						name: "platformFeeRevenue";
+						docs: [
+							"Platform fees escrowed in the market vault until the market resolves"
+						];
+						type: "u64";
+					},
+					{
+						name: "marketConfig";
+						type: "pubkey";
+					}
+				];
+			};
+		},
+		{
+			name: "marketStatus";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "proposed";
+					},
+					{
+						name: "approved";
+					},
+					{
+						name: "trading";
+					},
+					{
+						name: "closed";
+					},
+					{
+						name: "resolved";
+					},
+					{
+						name: "disputed";
+					},
+					{
+						name: "settled";
+					},
+					{
+						name: "voided";
+					},
+					{
+						name: "dismissed";
+					},
+					{
+						name: "withdrawn";
+					}
+				];
+			};
+		},
+		{
+			name: "marketTombstone";
+			docs: ["Left behind by a dismissed market so its ID stays taken"];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "bump";
+						type: "u8";
+					},
+					{
+						name: "marketId";
+						type: "u64";
+					},
+					{
+						name: "dismissedAt";
+						type: "i64";
+					},
+					{
+						name: "reason";
+						type: {
+							defined: {
+								name: "dismissReason";
+							};
+						};
+					},
+					{
+						name: "creator";
+						type: "pubkey";
+					}
+				];
+			};
+		},
+		{
+			docs: [
+				"Carries the market schedule and pricing as they stand after the update"
+			];
+			name: "marketUpdated";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "updatedBy";
+						type: "pubkey";
+					},
+					{
+						docs: ["Revision counter after this update, starting at 1"];
+						name: "revision";
+						type: "u16";
+					},
+					{
+						name: "startTime";
+						type: "i64";
+					},
+					{
+						name: "endTime";
+						type: "i64";
+					},
+					{
+						name: "resolutionDeadline";
+						type: "i64";
+					},
+					{
+						name: "minPredictionPrice";
+						type: "u64";
+					},
+					{
+						name: "sigmaMin";
+						type: "u64";
+					},
+					{
+						name: "sigmaMax";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "marketVoided";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "refundPolicy";
+						type: {
+							defined: {
+								name: "feeRefundPolicy";
+							};
+						};
+					},
+					{
+						docs: [
+							"Whether the market was voided for missing its resolution deadline"
+						];
+						name: "isExpired";
+						type: "bool";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "platformConfig";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "bump";
+						type: "u8";
+					},
+					{
+						name: "treasuryBump";
+						type: "u8";
+					},
+					{
+						name: "platformId";
+						docs: [
+							"Stable ID the config is addressed by, so the admin key can change"
+						];
+						type: "u64";
+					},
+					{
+						name: "creatorFeeBps";
+						type: "u16";
+					},
+					{
+						name: "platformFeeBps";
+						type: "u16";
+					},
+					{
+						name: "marketProposalFee";
+						type: "u64";
+					},
+					{
+						name: "dismissalRefundBps";
+						docs: [
+							"Share of the proposal fee refunded when a market is dismissed"
+						];
+						type: "u16";
+					},
+					{
+						name: "spamDismissalRefundBps";
+						docs: [
+							"Share of the proposal fee refunded when a market is dismissed as spam"
+						];
+						type: "u16";
+					},
+					{
+						name: "withdrawalRefundBps";
+						docs: [
+							"Share of the proposal fee refunded when the creator withdraws a proposal"
+						];
+						type: "u16";
+					},
+					{
+						name: "disputePeriod";
+						type: "i64";
+					},
+					{
+						name: "disputeBond";
+						type: "u64";
+					},
+					{
+						name: "disputeRewardBps";
+						type: "u16";
+					},
+					{
+						name: "resolverGracePeriod";
+						type: "i64";
+					},
+					{
+						name: "settlementGracePeriod";
+						docs: [
+							"Time after the dispute window for settling a resolved market before it can be expired"
+						];
+						type: "i64";
+					},
+					{
+						name: "admin";
+						docs: ["Super-admin, who grants and revokes every other role"];
+						type: "pubkey";
+					},
+					{
+						name: "pendingAdmin";
+						docs: [
+							"Admin proposed by the current one, who takes over once they accept"
+						];
+						type: {
+							option: "pubkey";
+						};
+					},
+					{
+						name: "approver";
+						docs: ["Approves, dismisses and edits market proposals"];
+						type: "pubkey";
+					},
+					{
+						name: "resolver";
+						docs: [
+							"Resolves markets, those with a designated resolver once the grace period is over, amends",
+							"resolutions of markets without one, and posts settlement roots"
+						];
+						type: "pubkey";
+					},
+					{
+						name: "treasurer";
+						docs: ["Withdraws platform fees and updates the fee settings"];
+						type: "pubkey";
+					},
+					{
+						name: "pauser";
+						docs: [
+							"Pauses new proposals, approvals and predictions in an emergency"
+						];
+						type: "pubkey";
+					},
+					{
+						name: "arbiter";
+						docs: ["Settles disputes and resolution challenges"];
+						type: "pubkey";
+					},
+					{
+						name: "isPaused";
+						type: "bool";
+					},
+					{
+						name: "namespace";
+						docs: [
+							"Key the committee and market PDAs are derived from: the config's own address, or the",
+							"address of the admin-keyed config it was migrated from"
+						];
+						type: "pubkey";
+					}
+				];
+			};
+		},
+		{
+			docs: ["Carries the whole platform config as it stands after the update"];
+			name: "platformConfigUpdated";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
+					},
+					{
+						name: "creatorFeeBps";
+						type: "u16";
+					},
+					{
+						name: "platformFeeBps";
+						type: "u16";
+					},
+					{
+						name: "marketProposalFee";
+						type: "u64";
+					},
+					{
+						name: "dismissalRefundBps";
+						type: "u16";
+					},
+					{
+						name: "spamDismissalRefundBps";
+						type: "u16";
+					},
+					{
+						name: "withdrawalRefundBps";
+						type: "u16";
+					},
+					{
+						name: "disputePeriod";
+						type: "i64";
+					},
+					{
+						name: "disputeBond";
+						type: "u64";
+					},
+					{
+						name: "disputeRewardBps";
+						type: "u16";
+					},
+					{
+						name: "resolverGracePeriod";
+						type: "i64";
+					},
+					{
+						name: "settlementGracePeriod";
+						type: "i64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "platformFeesCollected";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "treasurer";
+						type: "pubkey";
+					},
+					{
+						name: "amount";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "platformFeesWithdrawn";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
+					},
+					{
+						name: "treasurer";
+						type: "pubkey";
+					},
+					{
+						docs: ["`None` for SOL"];
+						name: "stakeMint";
+						type: {
+							option: "pubkey";
+						};
+					},
+					{
+						name: "amount";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "platformInitialized";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
+					},
+					{
+						name: "platformId";
+						type: "u64";
+					},
+					{
+						name: "admin";
+						type: "pubkey";
+					},
+					{
+						name: "creatorFeeBps";
+						type: "u16";
+					},
+					{
+						name: "platformFeeBps";
+						type: "u16";
+					},
+					{
+						name: "marketProposalFee";
+						type: "u64";
+					},
+					{
+						name: "dismissalRefundBps";
+						type: "u16";
+					},
+					{
+						name: "spamDismissalRefundBps";
+						type: "u16";
+					},
+					{
+						name: "withdrawalRefundBps";
+						type: "u16";
+					},
+					{
+						name: "disputePeriod";
+						type: "i64";
+					},
+					{
+						name: "disputeBond";
+						type: "u64";
+					},
+					{
+						name: "disputeRewardBps";
+						type: "u16";
+					},
+					{
+						name: "resolverGracePeriod";
+						type: "i64";
+					},
+					{
+						name: "settlementGracePeriod";
+						type: "i64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "platformMigrated";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
+					},
+					{
+						docs: [
+							"Admin-keyed config the platform was migrated from, now closed"
+						];
+						name: "legacyPlatformConfig";
+						type: "pubkey";
+					},
+					{
+						name: "platformId";
+						type: "u64";
+					},
+					{
+						name: "admin";
+						type: "pubkey";
+					},
+					{
+						docs: ["Lamports moved from the legacy treasury to the new one"];
+						name: "treasuryBalance";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "platformPauseSet";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
+					},
+					{
+						name: "pauser";
+						type: "pubkey";
+					},
+					{
+						name: "isPaused";
+						type: "bool";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "platformRole";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "approver";
+					},
+					{
+						name: "resolver";
+					},
+					{
+						name: "treasurer";
+					},
+					{
+						name: "pauser";
+					},
+					{
+						name: "arbiter";
+					}
+				];
+			};
+		},
+		{
+			name: "position";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "claimed";
+						type: "bool";
+					},
+					{
+						name: "bump";
+						type: "u8";
+					},
+					{
+						name: "stake";
+						type: "u64";
+					},
+					{
+						name: "platformFee";
+						type: "u64";
+					},
+					{
+						name: "creatorFee";
+						type: "u64";
+					},
+					{
+						name: "sigma";
+						type: "u64";
+					},
+					{
+						name: "index";
+						type: "u64";
+					},
+					{
+						name: "score";
+						type: {
+							option: "u128";
+						};
+					},
+					{
+						name: "scoredAmendment";
+						docs: ["Resolution amendment the score was computed against"];
+						type: "u16";
+					},
+					{
+						name: "reward";
+						type: {
+							option: "u64";
+						};
+					},
+					{
+						name: "timestamp";
+						type: "i64";
+					},
+					{
+						name: "prediction";
+						type: "i64";
+					},
+					{
+						name: "user";
+						type: "pubkey";
+					},
+					{
+						name: "market";
+						type: "pubkey";
+					}
+				];
+			};
 		},
 		{
-			name: "position";
-			discriminator: [170, 188, 143, 228, 122, 64, 247, 208];
-		}
-	];
-	errors: [
+			name: "positionsSettled";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						docs: ["Positions scored in this batch"];
+						name: "positions";
+						type: "u64";
+					},
+					{
+						name: "scoredPositions";
+						type: "u64";
+					},
+					{
+						name: "totalPositions";
+						type: "u64";
+					},
+					{
+						name: "totalScores";
+						type: "u128";
+					},
+					{
+						docs: [
+							"Whether this batch scored the last position and settled the market"
+						];
+						name: "isSettled";
+						type: "bool";
+					}
+				];
+				kind: "struct";
+			};
+		},
 		{
-			code: 6000;
-			name: "rewardAlreadyClaimed";
-			msg: "Position reward already claimed";
+			name: "predictionPlaced";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "position";
+						type: "pubkey";
+					},
+					{
+						name: "user";
+						type: "pubkey";
+					},
+					{
+						name: "index";
+						type: "u64";
+					},
+					{
+						name: "prediction";
+						type: "i64";
+					},
+					{
+						name: "grossStake";
+						type: "u64";
+					},
+					{
+						name: "platformFee";
+						type: "u64";
+					},
+					{
+						name: "creatorFee";
+						type: "u64";
+					},
+					{
+						docs: ["Stake the vault actually received after fees"];
+						name: "netStake";
+						type: "u64";
+					},
+					{
+						docs: ["Sigma in effect when the prediction was placed"];
+						name: "sigma";
+						type: "u64";
+					},
+					{
+						name: "timestamp";
+						type: "i64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "priceOracle";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "feedId";
+						docs: [
+							"Pyth feed ID every price update used by the market must carry"
+						];
+						type: {
+							array: ["u8", 32];
+						};
+					},
+					{
+						name: "exponent";
+						docs: [
+							"Decimal exponent the resolution is expressed in, e.g. `-2` for cents"
+						];
+						type: "i32";
+					},
+					{
+						name: "maxStaleness";
+						docs: [
+							"Longest delay after the end time at which a published price is still accepted"
+						];
+						type: "i64";
+					},
+					{
+						name: "sampling";
+						docs: [
+							"Resolve from prices sampled before the end time rather than a single price after it"
+						];
+						type: {
+							option: {
+								defined: {
+									name: "priceSampling";
+								};
+							};
+						};
+					}
+				];
+			};
+		},
+		{
+			name: "priceSample";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "publishTime";
+						type: "i64";
+					},
+					{
+						name: "price";
+						docs: ["Price already scaled to the market's exponent"];
+						type: "i64";
+					}
+				];
+			};
+		},
+		{
+			name: "priceSampleRecorded";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "publishTime";
+						type: "i64";
+					},
+					{
+						docs: ["Price already scaled to the market's exponent"];
+						name: "price";
+						type: "i64";
+					},
+					{
+						name: "samples";
+						type: "u16";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "priceSamples";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "bump";
+						type: "u8";
+					},
+					{
+						name: "nextIndex";
+						docs: ["Slot the next sample overwrites once the buffer is full"];
+						type: "u16";
+					},
+					{
+						name: "lastPublishTime";
+						type: "i64";
+					},
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "samples";
+						type: {
+							vec: {
+								defined: {
+									name: "priceSample";
+								};
+							};
+						};
+					}
+				];
+			};
+		},
+		{
+			name: "priceSamplesInitialized";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "payer";
+						type: "pubkey";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "priceSampling";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "window";
+						docs: ["Length of the sampling window that closes at the end time"];
+						type: "i64";
+					},
+					{
+						name: "minInterval";
+						docs: ["Minimum publish time gap between two consecutive samples"];
+						type: "i64";
+					},
+					{
+						name: "minSamples";
+						docs: [
+							"Fewest samples the buffer must hold before the market can resolve"
+						];
+						type: "u16";
+					},
+					{
+						name: "aggregation";
+						type: {
+							defined: {
+								name: "sampleAggregation";
+							};
+						};
+					}
+				];
+			};
+		},
+		{
+			name: "proposalWithdrawn";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "marketId";
+						type: "u64";
+					},
+					{
+						name: "creator";
+						type: "pubkey";
+					},
+					{
+						name: "proposalFeeRefund";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "resolutionAmended";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "amendedBy";
+						type: "pubkey";
+					},
+					{
+						docs: ["Amendment counter after this amendment, starting at 1"];
+						name: "amendment";
+						type: "u16";
+					},
+					{
+						name: "previousResolution";
+						type: "i64";
+					},
+					{
+						name: "resolution";
+						type: "i64";
+					},
+					{
+						name: "timestamp";
+						type: "i64";
+					}
+				];
+				kind: "struct";
+			};
 		},
 		{
-			code: 6001;
-			name: "invalidDecay";
-			msg: "The decay factor must be greater than zero";
-		}
-	];
-	types: [
-		{
-			name: "marketConfig";
+			name: "resolutionChallengeSettled";
 			type: {
-				kind: "struct";
 				fields: [
 					{
-						name: "bump";
-						type: "u8";
+						name: "market";
+						type: "pubkey";
 					},
 					{
-						name: "vaultBump";
-						type: "u8";
+						name: "arbiter";
+						type: "pubkey";
 					},
 					{
-						name: "marketId";
+						name: "challenger";
+						type: "pubkey";
+					},
+					{
+						name: "challengeSucceeds";
+						type: "bool";
+					},
+					{
+						docs: [
+							"Creator bond slashed, part of it to the challenger and the rest to the treasury"
+						];
+						name: "creatorBondSlashed";
 						type: "u64";
 					},
 					{
-						name: "startTime";
+						docs: ["Share of the slashed creator bond paid to the challenger"];
+						name: "challengerReward";
+						type: "u64";
+					},
+					{
+						docs: ["Challenger bond slashed to the treasury"];
+						name: "challengerBondSlashed";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "resolutionChallenged";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "challenger";
+						type: "pubkey";
+					},
+					{
+						name: "proposedResolution";
 						type: "i64";
 					},
 					{
-						name: "endTime";
+						name: "challengedResolution";
 						type: "i64";
 					},
 					{
-						name: "minPredictionPrice";
+						name: "bond";
 						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "resolutionProposed";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
 					},
 					{
-						name: "question";
-						type: "string";
+						name: "creator";
+						type: "pubkey";
 					},
 					{
-						name: "description";
-						type: "string";
+						name: "resolution";
+						type: "i64";
 					},
 					{
-						name: "creator";
-						type: "pubkey";
+						name: "creatorBond";
+						type: "u64";
 					},
 					{
-						name: "marketState";
-						type: "pubkey";
+						name: "proposalEndsAt";
+						type: "i64";
 					}
 				];
+				kind: "struct";
 			};
 		},
 		{
-			name: "marketState";
+			name: "resolverCommittee";
 			type: {
 				kind: "struct";
 				fields: [
@@ -1644,132 +8920,264 @@ export type CurneyMarkets = {
 						type: "u8";
 					},
 					{
-						name: "decay";
+						name: "threshold";
+						type: "u8";
+					},
+					{
+						name: "tolerance";
 						type: "u64";
 					},
 					{
-						name: "isApproved";
-						type: "bool";
+						name: "members";
+						type: {
+							vec: "pubkey";
+						};
 					},
 					{
-						name: "isResolved";
-						type: "bool";
+						name: "flagCounts";
+						docs: [
+							"Number of times each member's attestation was flagged as an outlier, in member order"
+						];
+						type: {
+							vec: "u16";
+						};
+					}
+				];
+			};
+		},
+		{
+			docs: ["Carries the whole committee as it stands after the change"];
+			name: "resolverCommitteeUpdated";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
 					},
 					{
-						name: "resolution";
+						name: "members";
 						type: {
-							option: "i64";
+							vec: "pubkey";
 						};
 					},
 					{
-						name: "totalPool";
-						type: "u64";
+						name: "threshold";
+						type: "u8";
 					},
 					{
-						name: "totalPositions";
+						name: "tolerance";
 						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "rewardClaimed";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
 					},
 					{
-						name: "totalScores";
+						name: "position";
+						type: "pubkey";
+					},
+					{
+						name: "user";
+						type: "pubkey";
+					},
+					{
+						docs: ["`None` for markets settled by merkle root"];
+						name: "score";
 						type: {
 							option: "u128";
 						};
 					},
 					{
-						name: "creatorFeeRevenue";
+						name: "reward";
 						type: "u64";
-					},
-					{
-						name: "marketConfig";
-						type: "pubkey";
 					}
 				];
+				kind: "struct";
 			};
 		},
 		{
-			name: "platformConfig";
+			name: "roleGranted";
 			type: {
-				kind: "struct";
 				fields: [
 					{
-						name: "bump";
-						type: "u8";
+						name: "platformConfig";
+						type: "pubkey";
 					},
 					{
-						name: "treasuryBump";
-						type: "u8";
+						name: "role";
+						type: {
+							defined: {
+								name: "platformRole";
+							};
+						};
 					},
 					{
-						name: "creatorFeeBps";
-						type: "u16";
+						name: "previousHolder";
+						type: "pubkey";
 					},
 					{
-						name: "platformFeeBps";
-						type: "u16";
+						name: "holder";
+						type: "pubkey";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "roleRevoked";
+			type: {
+				fields: [
+					{
+						name: "platformConfig";
+						type: "pubkey";
 					},
 					{
-						name: "marketProposalFee";
-						type: "u64";
+						name: "role";
+						type: {
+							defined: {
+								name: "platformRole";
+							};
+						};
 					},
 					{
-						name: "admin";
+						docs: ["Holder the role was taken from, it is back with the admin"];
+						name: "holder";
 						type: "pubkey";
 					}
 				];
+				kind: "struct";
 			};
 		},
 		{
-			name: "position";
+			name: "sampleAggregation";
 			type: {
-				kind: "struct";
-				fields: [
+				kind: "enum";
+				variants: [
 					{
-						name: "claimed";
-						type: "bool";
+						name: "timeWeightedAverage";
 					},
 					{
-						name: "bump";
-						type: "u8";
+						name: "median";
+					}
+				];
+			};
+		},
+		{
+			name: "scoringCurve";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "gaussian";
 					},
 					{
-						name: "stake";
-						type: "u64";
+						name: "laplace";
 					},
 					{
-						name: "decay";
-						type: "u64";
+						name: "cauchy";
 					},
 					{
-						name: "index";
-						type: "u64";
+						name: "tent";
+					}
+				];
+			};
+		},
+		{
+			name: "settlementMode";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "onChain";
 					},
 					{
-						name: "reward";
+						name: "merkleRoot";
+					}
+				];
+			};
+		},
+		{
+			name: "settlementRootPosted";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
+					},
+					{
+						name: "resolver";
+						type: "pubkey";
+					},
+					{
+						name: "root";
 						type: {
-							option: "u64";
+							array: ["u8", 32];
 						};
 					},
 					{
-						name: "timestamp";
-						type: "i64";
+						name: "totalPayout";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "stakeRefunded";
+			type: {
+				fields: [
+					{
+						name: "market";
+						type: "pubkey";
 					},
 					{
-						name: "prediction";
-						type: "i64";
+						name: "position";
+						type: "pubkey";
 					},
 					{
 						name: "user";
 						type: "pubkey";
 					},
 					{
-						name: "market";
-						type: "pubkey";
+						name: "amount";
+						type: "u64";
+					}
+				];
+				kind: "struct";
+			};
+		},
+		{
+			name: "stakeWeighting";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "unweighted";
+					},
+					{
+						name: "linear";
+					},
+					{
+						name: "squareRoot";
 					}
 				];
 			};
 		}
 	];
 	constants: [
+		{
+			name: "disputeSeed";
+			type: "bytes";
+			value: "[100, 105, 115, 112, 117, 116, 101]";
+		},
 		{
 			name: "marketConfigSeed";
 			type: "bytes";
@@ -1780,6 +9188,11 @@ export type CurneyMarkets = {
 			type: "bytes";
 			value: "[109, 97, 114, 107, 101, 116, 45, 115, 116, 97, 116, 101]";
 		},
+		{
+			name: "marketTombstoneSeed";
+			type: "bytes";
+			value: "[109, 97, 114, 107, 101, 116, 45, 116, 111, 109, 98, 115, 116, 111, 110, 101]";
+		},
 		{
 			name: "marketVaultSeed";
 			type: "bytes";
@@ -1799,6 +9212,16 @@ export type CurneyMarkets = {
 			name: "positionSeed";
 			type: "bytes";
 			value: "[112, 111, 115, 105, 116, 105, 111, 110]";
+		},
+		{
+			name: "priceSamplesSeed";
+			type: "bytes";
+			value: "[112, 114, 105, 99, 101, 45, 115, 97, 109, 112, 108, 101, 115]";
+		},
+		{
+			name: "resolverCommitteeSeed";
+			type: "bytes";
+			value: "[114, 101, 115, 111, 108, 118, 101, 114, 45, 99, 111, 109, 109, 105, 116, 116, 101, 101]";
 		}
 	];
 };
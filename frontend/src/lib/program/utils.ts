@@ -8,17 +8,6 @@ import {
 import IDL from "./idl.json";
 import type { CurneyMarkets } from "./types";
 
-const FIXED_POINT_SCALE = 1e9;
-const DECAY_NORMALIZATION_FACTOR = 3600;
-
-export const PLATFORM_CONFIG = new PublicKey(
-	import.meta.env.VITE_PLATFORM_CONFIG
-);
-
-export const PLATFORM_TREASURY = new PublicKey(
-	import.meta.env.VITE_PLATFORM_TREASURY
-);
-
 // export const connection = new Connection("http://127.0.0.1:8899", {
 // 	commitment: "confirmed",
 // });
@@ -29,54 +18,49 @@ export const connection = new Connection(clusterApiUrl("devnet"), {
 
 export const program = new anchor.Program<CurneyMarkets>(IDL, { connection });
 
-export const calculateTotalScores = async (
-	resolution: number,
-	program: anchor.Program<CurneyMarkets>,
-	marketConfig: anchor.web3.PublicKey
-): Promise<anchor.BN> => {
-	const allPositionAccounts = (await program.account.position.all()).filter(
-		(p) => p.account.market.toBase58() == marketConfig.toBase58()
+type MarketStateAccount = anchor.IdlAccounts<CurneyMarkets>["marketState"];
+
+export const PLATFORM_ID = new anchor.BN(import.meta.env.VITE_PLATFORM_ID);
+
+export const [PLATFORM_CONFIG] = anchor.web3.PublicKey.findProgramAddressSync(
+	[Buffer.from("platform-config"), PLATFORM_ID.toBuffer("le", 8)],
+	program.programId
+);
+
+export const [PLATFORM_TREASURY] =
+	anchor.web3.PublicKey.findProgramAddressSync(
+		[Buffer.from("platform-treasury"), PLATFORM_CONFIG.toBuffer()],
+		program.programId
 	);
 
-	if (allPositionAccounts.length === 0) return new anchor.BN(0);
-
-	let total = 0;
-	for (const pos of allPositionAccounts) {
-		const dist = Math.abs(pos.account.prediction.toNumber() - resolution);
-		const decay =
-			(DECAY_NORMALIZATION_FACTOR * pos.account.decay.toNumber()) /
-			FIXED_POINT_SCALE;
-		const exponent = -Math.pow(dist / decay, 2);
-		const score = Math.exp(exponent);
-		total += score * FIXED_POINT_SCALE;
-	}
+// Markets are addressed by the platform namespace, which is the platform
+// config itself unless the platform was migrated from an admin-keyed config
+export const PLATFORM_NAMESPACE = import.meta.env.VITE_PLATFORM_NAMESPACE
+	? new PublicKey(import.meta.env.VITE_PLATFORM_NAMESPACE)
+	: PLATFORM_CONFIG;
 
-	return new anchor.BN(total);
-};
+export const [EVENT_AUTHORITY] = anchor.web3.PublicKey.findProgramAddressSync(
+	[Buffer.from("__event_authority")],
+	program.programId
+);
 
-export const calculateReward = (
-	prediction: number,
-	resolution: number,
-	decay: number,
-	totalPool: number,
-	totalScores: number
-): anchor.BN => {
-	if (totalScores <= 0) return new anchor.BN(0);
-
-	const dist = Math.abs(prediction - resolution);
-	const decayFloat = (DECAY_NORMALIZATION_FACTOR * decay) / FIXED_POINT_SCALE;
-	const exponent = -Math.pow(dist / decayFloat, 2);
-	const score = Math.exp(exponent) * FIXED_POINT_SCALE;
-	const reward = (score * totalPool) / totalScores;
-	return new anchor.BN(Math.floor(reward));
-};
+export const getMarketStatus = (state?: MarketStateAccount) =>
+	state ? Object.keys(state.status)[0] : undefined;
+
+export const isMarketApproved = (state?: MarketStateAccount) =>
+	!!state &&
+	!["proposed", "dismissed", "withdrawn"].includes(getMarketStatus(state)!);
+
+export const isMarketResolved = (state?: MarketStateAccount) =>
+	!!state &&
+	["resolved", "disputed", "settled"].includes(getMarketStatus(state)!);
 
 export const getMarketConfigPDA = (marketId: string) => {
 	const [marketConfig] = anchor.web3.PublicKey.findProgramAddressSync(
 		[
 			Buffer.from("market-config"),
 			new anchor.BN(marketId).toBuffer("le", 8),
-			PLATFORM_CONFIG.toBuffer(),
+			PLATFORM_NAMESPACE.toBuffer(),
 		],
 		program.programId
 	);
@@ -88,7 +72,7 @@ export const getMarketStatePDA = (marketConfig: PublicKey) => {
 		[
 			Buffer.from("market-state"),
 			marketConfig.toBuffer(),
-			PLATFORM_CONFIG.toBuffer(),
+			PLATFORM_NAMESPACE.toBuffer(),
 		],
 		program.programId
 	);
@@ -103,6 +87,18 @@ export const getMarketVaultPDA = (marketConfig: PublicKey) => {
 	return marketVault;
 };
 
+export const getMarketTombstonePDA = (marketId: string) => {
+	const [marketTombstone] = anchor.web3.PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("market-tombstone"),
+			new anchor.BN(marketId).toBuffer("le", 8),
+			PLATFORM_NAMESPACE.toBuffer(),
+		],
+		program.programId
+	);
+	return marketTombstone;
+};
+
 export const getPositionPDA = (
 	currentIndex: number,
 	marketConfig: PublicKey,
@@ -159,7 +155,7 @@ export const fetchAllMarketStateAccounts = async () => {
 export const fetchAllApprovedMarketStateAccounts = async () => {
 	try {
 		return (await fetchAllMarketStateAccounts()).filter(
-			(m) => m.account.isApproved
+			(m) => isMarketApproved(m.account)
 		);
 	} catch {
 		return [];
@@ -169,7 +165,7 @@ export const fetchAllApprovedMarketStateAccounts = async () => {
 export const fetchAllResolvedMarketStateAccounts = async () => {
 	try {
 		return (await fetchAllApprovedMarketStateAccounts()).filter(
-			(m) => m.account.isResolved
+			(m) => isMarketResolved(m.account)
 		);
 	} catch {
 		return [];
@@ -284,7 +280,7 @@ export const fetchAllMarketAccounts = async () => {
 export const fetchAllApprovedMarketAccounts = async () => {
 	const markets = await fetchAllMarketAccounts();
 	if (!markets) return [];
-	return markets.filter((m) => m.state.isApproved);
+	return markets.filter((m) => isMarketApproved(m.state));
 };
 
 export const fetchPositionAccount = async (position: string) => {
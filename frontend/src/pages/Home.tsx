@@ -5,7 +5,10 @@ import { Button } from "@radix-ui/themes";
 import Header from "../components/Header";
 import Footer from "../components/Footer";
 import MarketList from "../components/MarketList";
-import { fetchAllApprovedMarketAccounts } from "../lib/program/utils";
+import {
+	fetchAllApprovedMarketAccounts,
+	isMarketResolved,
+} from "../lib/program/utils";
 import { convertTimestamp } from "../lib/helpers";
 import { useTimeSync } from "../context/TimeSyncProvider";
 
@@ -67,9 +70,9 @@ const Home: React.FC = () => {
 			new Date() >
 				new Date(
 					convertTimestamp(m.config.endTime.toNumber(), timeOffsetMs)
-				) && !m.state.isResolved
+				) && !isMarketResolved(m.state)
 	);
-	const resolvedMarkets = markets?.filter((m) => m.state.isResolved);
+	const resolvedMarkets = markets?.filter((m) => isMarketResolved(m.state));
 
 	return (
 		<div className="min-h-screen bg-gray-50">
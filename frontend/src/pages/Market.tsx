@@ -14,6 +14,8 @@ import PredictionSpreadGraph from "../components/PredictionSpreadGraph";
 import {
 	fetchAllUserPositionAccountsByMarket,
 	fetchMarketAccount,
+	isMarketApproved,
+	isMarketResolved,
 } from "../lib/program/utils";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { useTimeSync } from "../context/TimeSyncProvider";
@@ -125,7 +127,7 @@ const Market: React.FC = () => {
 	}
 
 	const canBet =
-		market.state.isApproved &&
+		isMarketApproved(market.state) &&
 		new Date() >=
 			new Date(
 				convertTimestamp(
@@ -188,7 +190,7 @@ const Market: React.FC = () => {
 					<span>•</span>
 					<span
 						className={`flex capitalize items-center gap-1 ${
-							market.state.isResolved
+							isMarketResolved(market.state)
 								? "text-red-400"
 								: new Date() <
 										new Date(
@@ -204,14 +206,14 @@ const Market: React.FC = () => {
 												timeOffsetMs
 											)
 										) &&
-								  market.state.isApproved
+								  isMarketApproved(market.state)
 								? "text-green-400"
 								: "text-amber-400"
 						}`}
 					>
 						<span
 							className={`w-2 h-2 rounded-full ${
-								market.state.isResolved
+								isMarketResolved(market.state)
 									? "bg-red-400"
 									: new Date() <
 											new Date(
@@ -227,12 +229,12 @@ const Market: React.FC = () => {
 													timeOffsetMs
 												)
 											) &&
-									  market.state.isApproved
+									  isMarketApproved(market.state)
 									? "bg-green-400"
 									: "bg-amber-400"
 							}`}
 						></span>
-						{market.state.isResolved
+						{isMarketResolved(market.state)
 							? "Resolved"
 							: new Date() <
 									new Date(
@@ -248,7 +250,7 @@ const Market: React.FC = () => {
 											timeOffsetMs
 										)
 									) &&
-							  market.state.isApproved
+							  isMarketApproved(market.state)
 							? "Live"
 							: new Date() <=
 							  new Date(
@@ -264,7 +266,7 @@ const Market: React.FC = () => {
 
 				<div className="grid grid-cols-1 lg:grid-cols-3 gap-10">
 					<div className="lg:col-span-2 space-y-10 order-1 lg:order-1">
-						{market.state.isResolved && (
+						{isMarketResolved(market.state) && (
 							<PayoutGraph className="w-full" />
 						)}
 
@@ -304,7 +306,7 @@ const Market: React.FC = () => {
 									<p>Resolution</p>
 									<p
 										className={`font-medium ${
-											market.state.isResolved
+											isMarketResolved(market.state)
 												? "text-lime-500"
 												: "text-amber-500"
 										}`}
@@ -382,7 +384,7 @@ const Market: React.FC = () => {
 							) : (
 								<div className="bg-white border border-gray-200 shadow-sm rounded-xl p-6">
 									<h3 className="text-lg font-semibold text-gray-900 mb-2">
-										{market.state.isResolved
+										{isMarketResolved(market.state)
 											? "Market Resolved"
 											: new Date() >=
 											  new Date(
@@ -395,7 +397,7 @@ const Market: React.FC = () => {
 											: "Market Not Started"}
 									</h3>
 									<p className="text-gray-600">
-										{market.state.isResolved
+										{isMarketResolved(market.state)
 											? "This market has been resolved. Check your profile for rewards."
 											: new Date() >=
 											  new Date(
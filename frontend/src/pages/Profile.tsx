@@ -15,6 +15,7 @@ import {
 	fetchAllUserPositionAccounts,
 	fetchPositionAccount,
 	fetchMarketAccount,
+	isMarketResolved,
 } from "../lib/program/utils";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 
@@ -194,16 +195,16 @@ const Profile: React.FC = () => {
 			const isUnclaimedPositionReward =
 				!!userPosition && !userPosition.claimed;
 
-			const isCreatorUnresolved = isCreator && !market?.state.isResolved;
+			const isCreatorUnresolved = isCreator && !isMarketResolved(market?.state);
 			const isWithdrawableCreatorRevenue =
 				isCreator &&
-				market?.state.isResolved &&
+				isMarketResolved(market?.state) &&
 				market.state.creatorFeeRevenue.toNumber() > 0;
 			const isCreatorActive =
 				isCreatorUnresolved || isWithdrawableCreatorRevenue;
 
 			const isHistory =
-				market?.state.isResolved &&
+				isMarketResolved(market?.state) &&
 				// If user is predictor: position is claimed OR user lost
 				(!userPosition ||
 					userPosition.claimed ||
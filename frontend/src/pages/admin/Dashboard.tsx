@@ -11,7 +11,11 @@ import {
 	formatCurrency,
 	formatDate,
 } from "../../lib/helpers";
-import { fetchAllMarketAccounts } from "../../lib/program/utils";
+import {
+	fetchAllMarketAccounts,
+	isMarketApproved,
+	isMarketResolved,
+} from "../../lib/program/utils";
 
 const AdminDashboard: React.FC = () => {
 	const { timeOffsetMs } = useTimeSync();
@@ -36,10 +40,10 @@ const AdminDashboard: React.FC = () => {
 		loadMarkets();
 	}, []);
 
-	const pendingMarkets = markets.filter((m) => !m.state!.isApproved);
+	const pendingMarkets = markets.filter((m) => !isMarketApproved(m.state!));
 	const unresolvedMarkets = markets.filter(
 		(m) =>
-			!m.state!.isResolved &&
+			!isMarketResolved(m.state!) &&
 			Date.now() > convertTimestamp(m.config.endTime, timeOffsetMs)
 	);
 
@@ -18,11 +18,17 @@ import { ResolveMarketFormSchema } from "../../lib/types";
 import type { MarketFormData, ResolveFormData } from "../../lib/types";
 import {
 	approveMarket,
+	collectPlatformFees,
 	dismissMarket,
 	resolveMarket,
+	settlePositions,
 	updateMarketConfig,
 } from "../../lib/program/instructions";
-import { fetchMarketAccount } from "../../lib/program/utils";
+import {
+	fetchMarketAccount,
+	isMarketApproved,
+	isMarketResolved,
+} from "../../lib/program/utils";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { useTimeSync } from "../../context/TimeSyncProvider";
 
@@ -39,6 +45,8 @@ const AdminMarketDetail: React.FC = () => {
 	const [resolving, setResolving] = useState(false);
 	const [approving, setApproving] = useState(false);
 	const [dismissing, setDismissing] = useState(false);
+	const [settling, setSettling] = useState(false);
+	const [collecting, setCollecting] = useState(false);
 
 	const {
 		register,
@@ -128,7 +136,7 @@ const AdminMarketDetail: React.FC = () => {
 		try {
 			const tx = await updateMarketConfig({
 				marketConfig: id,
-				admin: userPublicKey,
+				authority: userPublicKey,
 				question: data.question,
 				description: data.description,
 				startTime: new Date(data.startTime).getTime() / 1000,
@@ -155,12 +163,16 @@ const AdminMarketDetail: React.FC = () => {
 	};
 
 	const handleApproveMarket = async () => {
-		if (!userPublicKey || !signTransaction) return;
+		if (!userPublicKey || !signTransaction || !market) return;
 
 		setApproving(true);
 
 		try {
-			const tx = await approveMarket(id, userPublicKey);
+			const tx = await approveMarket(
+				id,
+				market.config.revision,
+				userPublicKey
+			);
 			const signedTx = await signTransaction(tx);
 			const signature = await connection.sendRawTransaction(
 				signedTx.serialize()
@@ -189,7 +201,9 @@ const AdminMarketDetail: React.FC = () => {
 		try {
 			const tx = await dismissMarket(
 				id,
+				market.config.marketId.toString(),
 				market.config.creator.toBase58(),
+				{ unclear: {} },
 				userPublicKey
 			);
 			const signedTx = await signTransaction(tx);
@@ -212,6 +226,60 @@ const AdminMarketDetail: React.FC = () => {
 		}
 	};
 
+	const handleSettlePositions = async () => {
+		if (!userPublicKey || !signTransaction) return;
+
+		setSettling(true);
+
+		try {
+			const tx = await settlePositions(id, userPublicKey);
+			const signedTx = await signTransaction(tx);
+			const signature = await connection.sendRawTransaction(
+				signedTx.serialize()
+			);
+			const latestBlockhash = await connection.getLatestBlockhash();
+			await connection.confirmTransaction({
+				blockhash: latestBlockhash.blockhash,
+				lastValidBlockHeight: latestBlockhash.lastValidBlockHeight,
+				signature: signature,
+			});
+			setMarket(await fetchMarketAccount(id));
+			toast.success("Positions settled successfully!");
+		} catch (error) {
+			console.error("Failed to settle positions:", error);
+			toast.error("Failed to settle positions");
+		} finally {
+			setSettling(false);
+		}
+	};
+
+	const handleCollectPlatformFees = async () => {
+		if (!userPublicKey || !signTransaction) return;
+
+		setCollecting(true);
+
+		try {
+			const tx = await collectPlatformFees(id, userPublicKey);
+			const signedTx = await signTransaction(tx);
+			const signature = await connection.sendRawTransaction(
+				signedTx.serialize()
+			);
+			const latestBlockhash = await connection.getLatestBlockhash();
+			await connection.confirmTransaction({
+				blockhash: latestBlockhash.blockhash,
+				lastValidBlockHeight: latestBlockhash.lastValidBlockHeight,
+				signature: signature,
+			});
+			setMarket(await fetchMarketAccount(id));
+			toast.success("Platform fees collected successfully!");
+		} catch (error) {
+			console.error("Failed to collect platform fees:", error);
+			toast.error("Failed to collect platform fees");
+		} finally {
+			setCollecting(false);
+		}
+	};
+
 	if (loading) {
 		return (
 			<div className="min-h-screen bg-gray-50">
@@ -262,7 +330,7 @@ const AdminMarketDetail: React.FC = () => {
 							</h2>
 							<span
 								className={`flex capitalize items-center gap-1 ${
-									market.state.isResolved
+									isMarketResolved(market.state)
 										? "text-red-400"
 										: new Date() <
 												new Date(
@@ -278,14 +346,14 @@ const AdminMarketDetail: React.FC = () => {
 														timeOffsetMs
 													)
 												) &&
-										  market.state.isApproved
+										  isMarketApproved(market.state)
 										? "text-green-400"
 										: "text-amber-400"
 								}`}
 							>
 								<span
 									className={`w-2 h-2 rounded-full ${
-										market.state.isResolved
+										isMarketResolved(market.state)
 											? "bg-red-400"
 											: new Date() <
 													new Date(
@@ -301,12 +369,12 @@ const AdminMarketDetail: React.FC = () => {
 															timeOffsetMs
 														)
 													) &&
-											  market.state.isApproved
+											  isMarketApproved(market.state)
 											? "bg-green-400"
 											: "bg-amber-400"
 									}`}
 								></span>
-								{market.state.isResolved
+								{isMarketResolved(market.state)
 									? "Resolved"
 									: new Date() <
 											new Date(
@@ -322,7 +390,7 @@ const AdminMarketDetail: React.FC = () => {
 													timeOffsetMs
 												)
 											) &&
-									  market.state.isApproved
+									  isMarketApproved(market.state)
 									? "Live"
 									: new Date() <=
 									  new Date(
@@ -373,7 +441,7 @@ const AdminMarketDetail: React.FC = () => {
 							</div>
 						</div>
 
-						{!market.state.isApproved && (
+						{!isMarketApproved(market.state) && (
 							<div className="mt-4 pt-6">
 								<div className="flex justify-between items-center gap-4">
 									<Button
@@ -405,8 +473,8 @@ const AdminMarketDetail: React.FC = () => {
 							</div>
 						)}
 
-						{market.state.isResolved &&
-							market.state.resolution !== undefined && (
+						{isMarketResolved(market.state) &&
+							market.state.resolution && (
 								<div className="mt-6 p-4 bg-blue-50 rounded-lg">
 									<h3 className="font-semibold text-blue-900 mb-2">
 										Resolution
@@ -414,14 +482,14 @@ const AdminMarketDetail: React.FC = () => {
 									<p className="text-blue-800">
 										Final value:{" "}
 										<span className="font-bold">
-											{market.state.resolution}
+											{market.state.resolution.toString()}
 										</span>
 									</p>
 								</div>
 							)}
 					</div>
 
-					{!market.state.isApproved && (
+					{!isMarketApproved(market.state) && (
 						<div className="bg-white border border-gray-200 rounded-lg p-6">
 							<h2 className="text-xl font-bold text-gray-900 mb-4">
 								Update Market Config
@@ -439,8 +507,8 @@ const AdminMarketDetail: React.FC = () => {
 						</div>
 					)}
 
-					{market.state.isApproved &&
-						!market.state.isResolved &&
+					{isMarketApproved(market.state) &&
+						!isMarketResolved(market.state) &&
 						new Date() >=
 							new Date(market.config.endTime.toNumber()) && (
 							<div className="bg-white border border-gray-200 rounded-lg p-6">
@@ -491,6 +559,54 @@ const AdminMarketDetail: React.FC = () => {
 								</form>
 							</div>
 						)}
+
+					{isMarketResolved(market.state) && (
+						<div className="bg-white border border-gray-200 rounded-lg p-6">
+							<h2 className="text-xl font-bold text-gray-900 mb-4">
+								Settle Market
+							</h2>
+							<p className="text-gray-600 mb-6">
+								Once the dispute period is over, score the
+								positions in batches so rewards can be claimed,
+								then collect the platform fees held in the
+								market vault.
+							</p>
+
+							<div className="flex justify-between items-center gap-4">
+								<Button
+									type="button"
+									onClick={handleSettlePositions}
+									disabled={
+										settling ||
+										collecting ||
+										market.state.scoredPositions.gte(
+											market.state.totalPositions
+										)
+									}
+									className="flex-1 bg-lime-500 hover:bg-lime-600 text-white px-8 py-7 text-lg cursor-pointer disabled:bg-gray-400 disabled:cursor-not-allowed disabled:opacity-80"
+								>
+									{settling
+										? "Settling..."
+										: `Settle Positions (${market.state.scoredPositions.toNumber()}/${market.state.totalPositions.toNumber()})`}
+								</Button>
+
+								<Button
+									variant="soft"
+									onClick={handleCollectPlatformFees}
+									disabled={
+										settling ||
+										collecting ||
+										market.state.platformFeeRevenue.isZero()
+									}
+									className="flex-1 bg-lime-100 hover:bg-lime-200 text-lime-900 px-8 py-7 text-lg cursor-pointer disabled:bg-gray-400 disabled:text-white disabled:cursor-not-allowed disabled:opacity-80"
+								>
+									{collecting
+										? "Collecting..."
+										: "Collect Platform Fees"}
+								</Button>
+							</div>
+						</div>
+					)}
 				</div>
 			</main>
 
@@ -79,7 +79,7 @@ const PlatformConfig: React.FC = () => {
 
 		try {
 			const tx = await updatePlatformConfig({
-				admin: userPublicKey,
+				authority: userPublicKey,
 				platformFeeBps: formData.platformFeeBps,
 				creatorFeeBps: formData.creatorFeeBps,
 				marketProposalFee: formData.marketProposalFee,
@@ -0,0 +1,48 @@
+//! Helpers for optimistic resolution backed by a creator bond.
+//!
+//! Creator and challenger bonds are always in SOL and sit in the `market_vault` alongside
+//! any SOL stakes, so lamport payouts from the vault must leave them untouched.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::MARKET_VAULT_SEED;
+use crate::state::{MarketConfig, MarketState};
+
+/// Lamports of the market vault currently held as bonds.
+pub fn locked_bonds(market_config: &MarketConfig, market_state: &MarketState) -> u64 {
+    let creator_bond = if market_state.creator_bond_locked {
+        market_config.creator_bond
+    } else {
+        0
+    };
+    creator_bond.saturating_add(market_state.challenger_bond)
+}
+
+/// Pays `amount` lamports out of the market vault.
+pub fn pay_from_vault<'info>(
+    market_config: &Account<'info, MarketConfig>,
+    market_vault: &SystemAccount<'info>,
+    to: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let seeds = &[
+        MARKET_VAULT_SEED,
+        market_config.to_account_info().key.as_ref(),
+        &[market_config.vault_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_program = system_program.to_account_info();
+    let cpi_accounts = Transfer {
+        from: market_vault.to_account_info(),
+        to,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    transfer(cpi_ctx, amount)
+}
@@ -0,0 +1,77 @@
+//! Helpers for resolution by the platform's resolver committee.
+//!
+//! Each member attests to a market's outcome through `resolve_market`. Once `threshold`
+//! current members have attested, the market resolves to the median attestation. Any
+//! attestation further than `tolerance` from it is flagged, and its member's flag count on
+//! the committee goes up.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_COMMITTEE_MEMBERS;
+use crate::error::CommitteeError;
+use crate::state::{Attestation, ResolverCommittee};
+
+pub fn validate_committee(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= MAX_COMMITTEE_MEMBERS,
+        CommitteeError::InvalidMemberCount
+    );
+
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            !members[..i].contains(member),
+            CommitteeError::DuplicateMember
+        );
+    }
+
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        CommitteeError::InvalidThreshold
+    );
+
+    Ok(())
+}
+
+/// Median of the attested values. With an even count this is the lower of the two middle
+/// values, so the outcome is always a value some member attested to.
+pub fn median(attestations: &[Attestation]) -> Option<i64> {
+    let mut values: Vec<i64> = attestations.iter().map(|a| a.value).collect();
+    values.sort_unstable();
+    values.get(values.len().checked_sub(1)? / 2).copied()
+}
+
+/// Flags every attestation further than `tolerance` from `median`.
+pub fn flag_outliers(attestations: &mut [Attestation], median: i64, tolerance: u64) {
+    for attestation in attestations.iter_mut() {
+        attestation.is_flagged = attestation.value.abs_diff(median) > tolerance;
+    }
+}
+
+/// Adds one to the flag count of every member whose attestation is flagged.
+pub fn record_flags(committee: &mut ResolverCommittee, attestations: &[Attestation]) {
+    for attestation in attestations.iter().filter(|a| a.is_flagged) {
+        if let Some(i) = committee
+            .members
+            .iter()
+            .position(|member| *member == attestation.member)
+        {
+            committee.flag_counts[i] = committee.flag_counts[i].saturating_add(1);
+        }
+    }
+}
+
+/// Flag counts for a new member list, keeping the count of every member already on the
+/// committee and starting new members at zero.
+pub fn carry_flag_counts(committee: &ResolverCommittee, members: &[Pubkey]) -> Vec<u16> {
+    members
+        .iter()
+        .map(|member| {
+            committee
+                .members
+                .iter()
+                .position(|m| m == member)
+                .and_then(|i| committee.flag_counts.get(i).copied())
+                .unwrap_or(0)
+        })
+        .collect()
+}
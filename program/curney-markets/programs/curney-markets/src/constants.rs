@@ -15,17 +15,35 @@ pub const MARKET_STATE_SEED: &[u8] = b"market-state";
 #[constant]
 pub const MARKET_VAULT_SEED: &[u8] = b"market-vault";
 
+#[constant]
+pub const MARKET_TOMBSTONE_SEED: &[u8] = b"market-tombstone";
+
 #[constant]
 pub const POSITION_SEED: &[u8] = b"position";
 
+#[constant]
+pub const DISPUTE_SEED: &[u8] = b"dispute";
+
+#[constant]
+pub const RESOLVER_COMMITTEE_SEED: &[u8] = b"resolver-committee";
+
+pub const MAX_COMMITTEE_MEMBERS: usize = 10;
+
+#[constant]
+pub const PRICE_SAMPLES_SEED: &[u8] = b"price-samples";
+
+pub const MAX_PRICE_SAMPLES: usize = 64;
+
 pub const MARKET_QUESTION_MAX_LEN: usize = 256;
 
 pub const MARKET_DESCRIPTION_MAX_LEN: usize = 1024;
 
 pub const BASIS_POINT_SCALE: u16 = 10_000;
 
-pub const FIXED_POINT_SCALE: u64 = 1_000_000_000;
+/// Share of a slashed creator bond paid to a successful challenger, the rest goes to the treasury
+pub const CHALLENGER_BOND_SHARE_BPS: u16 = 5_000;
 
-pub const DECAY_DURATION_SCALE: u64 = 86_400;
+/// Settlement grace period given to platforms migrated from the admin-keyed layout
+pub const DEFAULT_SETTLEMENT_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
 
-pub const DECAY_NORMALIZATION_FACTOR: u64 = 3_600;
+pub const FIXED_POINT_SCALE: u64 = 1_000_000_000;
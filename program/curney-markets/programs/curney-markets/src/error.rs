@@ -19,6 +19,36 @@ pub enum PlatformError {
 
     #[msg("Platform treasury has no funds to withdraw")]
     NothingToWithdraw,
+
+    #[msg("Dispute period must not be negative")]
+    InvalidDisputePeriod,
+
+    #[msg("Dispute bond must be greater than zero")]
+    InvalidDisputeBond,
+
+    #[msg("Dispute reward BPS must be <= 10,000")]
+    InvalidDisputeRewardBps,
+
+    #[msg("Resolver grace period must not be negative")]
+    InvalidResolverGracePeriod,
+
+    #[msg("Dismissal refund BPS must be <= 10,000")]
+    InvalidDismissalRefundBps,
+
+    #[msg("Withdrawal refund BPS must be <= 10,000")]
+    InvalidWithdrawalRefundBps,
+
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+
+    #[msg("Account is not an admin-keyed platform config")]
+    InvalidLegacyPlatformConfig,
+
+    #[msg("The platform is paused")]
+    PlatformPaused,
+
+    #[msg("Settlement grace period must not be negative")]
+    InvalidSettlementGracePeriod,
 }
 
 #[error_code]
@@ -79,6 +109,92 @@ pub enum MarketError {
 
     #[msg("Math overflow occurred")]
     MathOverflow,
+
+    #[msg("Every position has already been settled")]
+    MarketAlreadySettled,
+
+    #[msg("Not every position has been settled yet")]
+    SettlementIncomplete,
+
+    #[msg("This action is not available in the market's settlement mode")]
+    InvalidSettlementMode,
+
+    #[msg("A settlement root has already been posted")]
+    SettlementRootAlreadyPosted,
+
+    #[msg("No settlement root has been posted yet")]
+    SettlementRootNotPosted,
+
+    #[msg("Settlement payout exceeds the market pool")]
+    PayoutExceedsPool,
+
+    #[msg("Market vault does not hold enough funds for this payout")]
+    InsufficientVaultBalance,
+
+    #[msg("Token-denominated markets require their token accounts")]
+    MissingTokenAccount,
+
+    #[msg("Stake mint does not match the market")]
+    InvalidStakeMint,
+
+    #[msg("Token account is not the expected associated token account")]
+    InvalidTokenAccount,
+
+    #[msg("Market has been voided")]
+    MarketVoided,
+
+    #[msg("Market has not been voided")]
+    MarketNotVoided,
+
+    #[msg("Market resolution deadline has passed")]
+    ResolutionDeadlinePassed,
+
+    #[msg("Market resolution deadline has not passed yet")]
+    ResolutionDeadlineNotReached,
+
+    #[msg("Market resolution is still open to disputes")]
+    ResolutionNotFinal,
+
+    #[msg("This action is not available in the market's resolution mode")]
+    InvalidResolutionMode,
+
+    #[msg("Oracle staleness must be greater than zero and its exponent within 18")]
+    InvalidOracleConfig,
+
+    #[msg("Only the market resolver can resolve the market during its grace period")]
+    ResolverGracePeriod,
+
+    #[msg("Creator bond resolution cannot be combined with an oracle")]
+    InvalidCreatorBond,
+
+    #[msg(
+        "Resolution can no longer be amended once claims have started or settlement has stalled"
+    )]
+    CorrectionWindowClosed,
+
+    #[msg("Market ID belongs to a dismissed market and cannot be reused")]
+    MarketIdDismissed,
+
+    #[msg("Market status does not allow this action")]
+    InvalidMarketStatus,
+
+    #[msg("Market cannot move to this status from its current one")]
+    InvalidStatusTransition,
+
+    #[msg("The creator can only edit the question, description, times and min prediction price")]
+    CreatorEditNotAllowed,
+
+    #[msg("The market was edited after the revision being approved")]
+    RevisionMismatch,
+
+    #[msg("The market can still be settled until its settlement grace period ends")]
+    SettlementGracePeriod,
+
+    #[msg("Sigma min must be greater than zero and no larger than sigma max")]
+    InvalidSigmaRange,
+
+    #[msg("Resolution deadline must be after the end time")]
+    InvalidResolutionDeadline,
 }
 
 #[error_code]
@@ -86,6 +202,125 @@ pub enum PositionError {
     #[msg("Position reward already claimed")]
     RewardAlreadyClaimed,
 
-    #[msg("The decay factor must be greater than zero")]
-    InvalidDecay,
+    #[msg("The sigma must be greater than zero")]
+    InvalidSigma,
+
+    #[msg("Position has already been settled")]
+    PositionAlreadySettled,
+
+    #[msg("Position has not been settled yet")]
+    PositionNotSettled,
+
+    #[msg("Position does not belong to this market")]
+    InvalidMarket,
+
+    #[msg("Merkle proof does not match the settlement root")]
+    InvalidProof,
+
+    #[msg("Position stake already refunded")]
+    StakeAlreadyRefunded,
+}
+
+#[error_code]
+pub enum DisputeError {
+    #[msg("The dispute window has closed")]
+    DisputeWindowClosed,
+
+    #[msg("Proposed resolution matches the current resolution")]
+    SameResolution,
+
+    #[msg("Challenger account specified is not the dispute challenger")]
+    InvalidChallenger,
+
+    #[msg("Dispute does not belong to this market")]
+    InvalidMarket,
+}
+
+#[error_code]
+pub enum OracleError {
+    #[msg("Account is not a valid price update")]
+    InvalidPriceFeed,
+
+    #[msg("Price update is not fully verified")]
+    UnverifiedPrice,
+
+    #[msg("Price was not published within the staleness window after the end time")]
+    PriceOutsideWindow,
+
+    #[msg("Price feed ID does not match the market")]
+    FeedIdMismatch,
+
+    #[msg("Price exponent cannot be mapped to the market exponent")]
+    InvalidExponent,
+
+    #[msg("Scaled price does not fit in a resolution")]
+    PriceOutOfRange,
+
+    #[msg("Market does not resolve from price samples")]
+    SamplingNotEnabled,
+
+    #[msg(
+        "Sampling window, interval and sample count must be positive, the count within the buffer"
+    )]
+    InvalidSampling,
+
+    #[msg("Price was not published within the sampling window")]
+    SampleOutsideWindow,
+
+    #[msg("Price was published too soon after the previous sample")]
+    SampleTooSoon,
+
+    #[msg("Market has no price samples")]
+    NoPriceSamples,
+
+    #[msg("Market does not have enough price samples to resolve")]
+    NotEnoughSamples,
+}
+
+#[error_code]
+pub enum CommitteeError {
+    #[msg("Committee must have between 1 and 10 members")]
+    InvalidMemberCount,
+
+    #[msg("Committee members must be unique")]
+    DuplicateMember,
+
+    #[msg("Threshold must be between 1 and the number of members")]
+    InvalidThreshold,
+
+    #[msg("Signer is not a member of the resolver committee")]
+    NotCommitteeMember,
+
+    #[msg("Member has already attested to this market")]
+    AlreadyAttested,
+}
+
+#[error_code]
+pub enum BondError {
+    #[msg("An outcome has already been proposed")]
+    ResolutionAlreadyProposed,
+
+    #[msg("No outcome has been proposed yet")]
+    ResolutionNotProposed,
+
+    #[msg("The liveness period has ended")]
+    LivenessPeriodEnded,
+
+    #[msg("The liveness period has not ended yet")]
+    LivenessPeriodNotEnded,
+
+    #[msg("The proposed outcome has already been challenged")]
+    AlreadyChallenged,
+
+    #[msg("The proposed outcome has not been challenged")]
+    NotChallenged,
+
+    #[msg("Challenged outcome matches the proposed outcome")]
+    SameResolution,
+
+    #[msg("Challenger account specified is not the market challenger")]
+    InvalidChallenger,
+
+    #[msg("Market holds no bonds to return")]
+    NoBondsLocked,
 }
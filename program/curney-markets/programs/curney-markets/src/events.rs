@@ -0,0 +1,357 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{DismissReason, FeeRefundPolicy, PlatformRole};
+
+#[event]
+pub struct PlatformInitialized {
+    pub platform_config: Pubkey,
+    pub platform_id: u64,
+    pub admin: Pubkey,
+    pub creator_fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub market_proposal_fee: u64,
+    pub dismissal_refund_bps: u16,
+    pub spam_dismissal_refund_bps: u16,
+    pub withdrawal_refund_bps: u16,
+    pub dispute_period: i64,
+    pub dispute_bond: u64,
+    pub dispute_reward_bps: u16,
+    pub resolver_grace_period: i64,
+    pub settlement_grace_period: i64,
+}
+
+/// Carries the whole platform config as it stands after the update
+#[event]
+pub struct PlatformConfigUpdated {
+    pub platform_config: Pubkey,
+    pub creator_fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub market_proposal_fee: u64,
+    pub dismissal_refund_bps: u16,
+    pub spam_dismissal_refund_bps: u16,
+    pub withdrawal_refund_bps: u16,
+    pub dispute_period: i64,
+    pub dispute_bond: u64,
+    pub dispute_reward_bps: u16,
+    pub resolver_grace_period: i64,
+    pub settlement_grace_period: i64,
+}
+
+#[event]
+pub struct PlatformMigrated {
+    pub platform_config: Pubkey,
+    /// Admin-keyed config the platform was migrated from, now closed
+    pub legacy_platform_config: Pubkey,
+    pub platform_id: u64,
+    pub admin: Pubkey,
+    /// Lamports moved from the legacy treasury to the new one
+    pub treasury_balance: u64,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub platform_config: Pubkey,
+    pub admin: Pubkey,
+    /// `None` when a pending transfer is cancelled
+    pub pending_admin: Option<Pubkey>,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub platform_config: Pubkey,
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+    /// Roles the previous admin held, now held by the new admin
+    pub reassigned_roles: Vec<PlatformRole>,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub platform_config: Pubkey,
+    pub role: PlatformRole,
+    pub previous_holder: Pubkey,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub platform_config: Pubkey,
+    pub role: PlatformRole,
+    /// Holder the role was taken from, it is back with the admin
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct PlatformPauseSet {
+    pub platform_config: Pubkey,
+    pub pauser: Pubkey,
+    pub is_paused: bool,
+}
+
+/// Carries the whole committee as it stands after the change
+#[event]
+pub struct ResolverCommitteeUpdated {
+    pub platform_config: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub tolerance: u64,
+}
+
+#[event]
+pub struct PlatformFeesCollected {
+    pub market: Pubkey,
+    pub treasurer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PlatformFeesWithdrawn {
+    pub platform_config: Pubkey,
+    pub treasurer: Pubkey,
+    /// `None` for SOL
+    pub stake_mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MarketProposed {
+    pub market: Pubkey,
+    pub market_id: u64,
+    pub creator: Pubkey,
+    pub proposal_fee: u64,
+    pub creator_bond: u64,
+    pub stake_mint: Option<Pubkey>,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub resolution_deadline: i64,
+    pub min_prediction_price: u64,
+    pub sigma_min: u64,
+    pub sigma_max: u64,
+}
+
+/// Carries the market schedule and pricing as they stand after the update
+#[event]
+pub struct MarketUpdated {
+    pub market: Pubkey,
+    pub updated_by: Pubkey,
+    /// Revision counter after this update, starting at 1
+    pub revision: u16,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub resolution_deadline: i64,
+    pub min_prediction_price: u64,
+    pub sigma_min: u64,
+    pub sigma_max: u64,
+}
+
+#[event]
+pub struct MarketApproved {
+    pub market: Pubkey,
+    pub revision: u16,
+    pub resolver: Option<Pubkey>,
+}
+
+#[event]
+pub struct MarketDismissed {
+    pub market: Pubkey,
+    pub market_id: u64,
+    pub creator: Pubkey,
+    pub reason: DismissReason,
+    pub proposal_fee_refund: u64,
+}
+
+#[event]
+pub struct ProposalWithdrawn {
+    pub market: Pubkey,
+    pub market_id: u64,
+    pub creator: Pubkey,
+    pub proposal_fee_refund: u64,
+}
+
+#[event]
+pub struct DisputeBondReturned {
+    pub market: Pubkey,
+    pub dispute: Pubkey,
+    pub challenger: Pubkey,
+    pub bond: u64,
+}
+
+#[event]
+pub struct MarketVoided {
+    pub market: Pubkey,
+    pub refund_policy: FeeRefundPolicy,
+    /// Whether the market was voided for missing its resolution deadline
+    pub is_expired: bool,
+}
+
+#[event]
+pub struct MarketResolved {
+    pub market: Pubkey,
+    pub resolution: i64,
+    pub dispute_ends_at: i64,
+}
+
+#[event]
+pub struct AttestationSubmitted {
+    pub market: Pubkey,
+    pub member: Pubkey,
+    pub value: i64,
+    /// Attestations from current members, including this one
+    pub attestations: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ResolutionProposed {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub resolution: i64,
+    pub creator_bond: u64,
+    pub proposal_ends_at: i64,
+}
+
+#[event]
+pub struct ResolutionChallenged {
+    pub market: Pubkey,
+    pub challenger: Pubkey,
+    pub proposed_resolution: i64,
+    pub challenged_resolution: i64,
+    pub bond: u64,
+}
+
+#[event]
+pub struct ResolutionChallengeSettled {
+    pub market: Pubkey,
+    pub arbiter: Pubkey,
+    pub challenger: Pubkey,
+    pub challenge_succeeds: bool,
+    /// Creator bond slashed, part of it to the challenger and the rest to the treasury
+    pub creator_bond_slashed: u64,
+    /// Share of the slashed creator bond paid to the challenger
+    pub challenger_reward: u64,
+    /// Challenger bond slashed to the treasury
+    pub challenger_bond_slashed: u64,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub market: Pubkey,
+    pub dispute: Pubkey,
+    pub challenger: Pubkey,
+    pub proposed_resolution: i64,
+    pub bond: u64,
+    pub open_disputes: u64,
+}
+
+#[event]
+pub struct DisputeSettled {
+    pub market: Pubkey,
+    pub dispute: Pubkey,
+    pub arbiter: Pubkey,
+    pub challenger: Pubkey,
+    pub overturned: bool,
+    /// Market resolution after the ruling
+    pub resolution: i64,
+    /// Reward paid from the treasury to a successful challenger
+    pub reward: u64,
+    /// Bond slashed to the treasury when the resolution is upheld
+    pub bond_slashed: u64,
+    pub open_disputes: u64,
+}
+
+#[event]
+pub struct BondsReturned {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub creator_bond: u64,
+    pub challenger: Option<Pubkey>,
+    pub challenger_bond: u64,
+}
+
+#[event]
+pub struct PriceSamplesInitialized {
+    pub market: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct PriceSampleRecorded {
+    pub market: Pubkey,
+    pub publish_time: i64,
+    /// Price already scaled to the market's exponent
+    pub price: i64,
+    pub samples: u16,
+}
+
+#[event]
+pub struct PositionsSettled {
+    pub market: Pubkey,
+    /// Positions scored in this batch
+    pub positions: u64,
+    pub scored_positions: u64,
+    pub total_positions: u64,
+    pub total_scores: u128,
+    /// Whether this batch scored the last position and settled the market
+    pub is_settled: bool,
+}
+
+#[event]
+pub struct SettlementRootPosted {
+    pub market: Pubkey,
+    pub resolver: Pubkey,
+    pub root: [u8; 32],
+    pub total_payout: u64,
+}
+
+#[event]
+pub struct ResolutionAmended {
+    pub market: Pubkey,
+    pub amended_by: Pubkey,
+    /// Amendment counter after this amendment, starting at 1
+    pub amendment: u16,
+    pub previous_resolution: i64,
+    pub resolution: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PredictionPlaced {
+    pub market: Pubkey,
+    pub position: Pubkey,
+    pub user: Pubkey,
+    pub index: u64,
+    pub prediction: i64,
+    pub gross_stake: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    /// Stake the vault actually received after fees
+    pub net_stake: u64,
+    /// Sigma in effect when the prediction was placed
+    pub sigma: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub market: Pubkey,
+    pub position: Pubkey,
+    pub user: Pubkey,
+    /// `None` for markets settled by merkle root
+    pub score: Option<u128>,
+    pub reward: u64,
+}
+
+#[event]
+pub struct StakeRefunded {
+    pub market: Pubkey,
+    pub position: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CreatorRevenueWithdrawn {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+}
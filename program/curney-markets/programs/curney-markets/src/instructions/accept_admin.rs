@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PLATFORM_CONFIG_SEED;
+use crate::error::PlatformError;
+use crate::events::AdminTransferred;
+use crate::state::{PlatformConfig, PlatformRole};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    /// Completes an admin transfer. Every role still held by the previous admin moves to the
+    /// new admin, so a rotated key keeps no privileges.
+    pub fn accept_admin(&mut self) -> Result<AdminTransferred> {
+        require!(
+            self.platform_config.pending_admin == Some(self.pending_admin.key()),
+            PlatformError::NotPendingAdmin
+        );
+
+        let previous_admin = self.platform_config.admin;
+        self.platform_config.admin = self.pending_admin.key();
+        self.platform_config.pending_admin = None;
+
+        let mut reassigned_roles = Vec::new();
+        for role in PlatformRole::ALL {
+            if self.platform_config.role_holder(role) == previous_admin {
+                self.platform_config
+                    .set_role_holder(role, self.pending_admin.key());
+                reassigned_roles.push(role);
+            }
+        }
+
+        Ok(AdminTransferred {
+            platform_config: self.platform_config.key(),
+            previous_admin,
+            admin: self.platform_config.admin,
+            reassigned_roles,
+        })
+    }
+}
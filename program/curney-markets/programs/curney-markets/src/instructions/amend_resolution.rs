@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::MarketError;
+use crate::events::ResolutionAmended;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AmendResolution<'info> {
+    #[account(mut)]
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AmendResolution<'info> {
+    /// Corrects a resolution until the first reward is claimed, as long as the market's
+    /// settlement grace period has not run out. Only the market's designated resolver, or the
+    /// platform resolver when there is none, can amend it. Any settlement already done against
+    /// the old resolution is discarded and the dispute period starts over.
+    pub fn amend_resolution(&mut self, resolution: i64) -> Result<ResolutionAmended> {
+        require!(
+            self.market_config.oracle.is_none() && self.market_config.creator_bond == 0,
+            MarketError::InvalidResolutionMode
+        );
+
+        let authority = self
+            .market_config
+            .resolver
+            .unwrap_or(self.platform_config.resolver);
+        require_keys_eq!(self.resolver.key(), authority, MarketError::Unauthorized);
+
+        require!(
+            !self.market_state.has_claims,
+            MarketError::CorrectionWindowClosed
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let status = self.market_state.require_status(
+            &self.market_config,
+            now,
+            &[MarketStatus::Resolved, MarketStatus::Settled],
+        )?;
+
+        // An amendment restarts the dispute window, so it cannot revive a market that is
+        // already past its settlement grace period and may be expired
+        require!(
+            now < self.market_state.settlement_deadline(&self.platform_config),
+            MarketError::CorrectionWindowClosed
+        );
+
+        if status == MarketStatus::Settled {
+            self.market_state
+                .transition(&self.market_config, now, MarketStatus::Resolved)?;
+        }
+
+        let previous_resolution = self
+            .market_state
+            .resolution
+            .ok_or(MarketError::MarketNotResolved)?;
+
+        let amendment = self
+            .market_state
+            .resolution_amendments
+            .checked_add(1)
+            .ok_or(MarketError::MathOverflow)?;
+
+        self.market_state.resolution = Some(resolution);
+        self.market_state.resolution_amendments = amendment;
+        self.market_state.dispute_ends_at = now
+            .checked_add(self.platform_config.dispute_period)
+            .ok_or(MarketError::MathOverflow)?;
+
+        // Positions scored against the old resolution are rescored by `settle_positions`
+        self.market_state.scored_positions = 0;
+        self.market_state.total_scores = 0;
+        self.market_state.settlement_root = None;
+        self.market_state.settlement_payout = 0;
+
+        Ok(ResolutionAmended {
+            market: self.market_config.key(),
+            amended_by: self.resolver.key(),
+            amendment,
+            previous_resolution,
+            resolution,
+            timestamp: now,
+        })
+    }
+}
@@ -1,63 +1,83 @@
 use anchor_lang::prelude::*;
 
+use crate::bond::pay_from_vault;
 use crate::constants::{
     MARKET_CONFIG_SEED, MARKET_DESCRIPTION_MAX_LEN, MARKET_QUESTION_MAX_LEN, MARKET_STATE_SEED,
-    PLATFORM_CONFIG_SEED,
+    MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED,
 };
-use crate::error::MarketError;
-use crate::state::{MarketConfig, MarketState, PlatformConfig};
+use crate::error::{MarketError, PlatformError};
+use crate::events::MarketApproved;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ApproveMarket<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub approver: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
+    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump = platform_config.treasury_bump)]
+    pub platform_treasury: SystemAccount<'info>,
+
     #[account(
-        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        mut,
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         bump = market_config.bump,
     )]
     pub market_config: Account<'info, MarketConfig>,
 
     #[account(
         mut,
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         bump = market_state.bump,
     )]
     pub market_state: Account<'info, MarketState>,
 
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ApproveMarket<'info> {
-    pub fn approve_market(&mut self) -> Result<()> {
+    /// Approves the market, optionally handing its resolution to a designated `resolver`
+    /// such as the creator, a data provider or an oracle adapter program. The escrowed
+    /// proposal fee moves from the market vault to the platform treasury.
+    ///
+    /// `revision` is the revision the approver reviewed, so a market edited in the meantime is
+    /// not approved by mistake.
+    pub fn approve_market(
+        &mut self,
+        resolver: Option<Pubkey>,
+        revision: u16,
+    ) -> Result<MarketApproved> {
         require!(
-            self.admin.key() == self.platform_config.admin,
-            MarketError::Unauthorized
+            !self.platform_config.is_paused,
+            PlatformError::PlatformPaused
         );
 
         require!(
-            !self.market_state.is_approved,
-            MarketError::MarketAlreadyApproved
+            self.approver.key() == self.platform_config.approver,
+            MarketError::Unauthorized
         );
 
         require!(
-            !self.market_state.is_resolved,
-            MarketError::MarketAlreadyResolved
+            self.market_config.revision == revision,
+            MarketError::RevisionMismatch
         );
 
         require!(
-            self.market_config.question.as_bytes().len() <= MARKET_QUESTION_MAX_LEN,
+            self.market_config.question.len() <= MARKET_QUESTION_MAX_LEN,
             MarketError::QuestionTooLong
         );
 
         require!(
-            self.market_config.description.as_bytes().len() <= MARKET_DESCRIPTION_MAX_LEN,
+            self.market_config.description.len() <= MARKET_DESCRIPTION_MAX_LEN,
             MarketError::DescriptionTooLong
         );
 
@@ -66,13 +86,46 @@ impl<'info> ApproveMarket<'info> {
             MarketError::InvalidEndTime
         );
 
+        require!(
+            self.market_config.end_time < self.market_config.resolution_deadline,
+            MarketError::InvalidResolutionDeadline
+        );
+
         require!(
             self.market_config.min_prediction_price > 0,
             MarketError::MinPredictionPriceZero
         );
 
-        self.market_state.is_approved = true;
+        require!(
+            self.market_config.sigma_min > 0
+                && self.market_config.sigma_min <= self.market_config.sigma_max,
+            MarketError::InvalidSigmaRange
+        );
+
+        require!(
+            resolver.is_none()
+                || (self.market_config.oracle.is_none() && self.market_config.creator_bond == 0),
+            MarketError::InvalidResolutionMode
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Approved)?;
+
+        self.market_config.resolver = resolver;
+
+        pay_from_vault(
+            &self.market_config,
+            &self.market_vault,
+            self.platform_treasury.to_account_info(),
+            &self.system_program,
+            self.market_config.proposal_fee,
+        )?;
 
-        Ok(())
+        Ok(MarketApproved {
+            market: self.market_config.key(),
+            revision,
+            resolver,
+        })
     }
 }
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED,
+};
+use crate::error::BondError;
+use crate::events::ResolutionChallenged;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ChallengeResolution<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ChallengeResolution<'info> {
+    /// Challenges the creator's proposed outcome with an alternative, locking the platform's
+    /// dispute bond in the market vault until the arbiter settles the challenge.
+    pub fn challenge_resolution(&mut self, resolution: i64) -> Result<ResolutionChallenged> {
+        let proposed_resolution = self
+            .market_state
+            .proposed_resolution
+            .ok_or(BondError::ResolutionNotProposed)?;
+
+        require!(
+            self.market_state.challenger.is_none(),
+            BondError::AlreadyChallenged
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Closed])?;
+
+        require!(
+            now < self.market_state.proposal_ends_at,
+            BondError::LivenessPeriodEnded
+        );
+
+        require!(resolution != proposed_resolution, BondError::SameResolution);
+
+        let bond = self.platform_config.dispute_bond;
+
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.challenger.to_account_info(),
+            to: self.market_vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer(cpi_ctx, bond)?;
+
+        self.market_state.challenger = Some(self.challenger.key());
+        self.market_state.challenged_resolution = Some(resolution);
+        self.market_state.challenger_bond = bond;
+
+        Ok(ResolutionChallenged {
+            market: self.market_config.key(),
+            challenger: self.challenger.key(),
+            proposed_resolution,
+            challenged_resolution: resolution,
+            bond,
+        })
+    }
+}
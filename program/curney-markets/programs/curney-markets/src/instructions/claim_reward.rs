@@ -1,57 +1,39 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::{
-    DECAY_NORMALIZATION_FACTOR, FIXED_POINT_SCALE, MARKET_CONFIG_SEED, MARKET_STATE_SEED,
-    MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED, POSITION_SEED,
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED, POSITION_SEED,
 };
 use crate::error::{MarketError, PositionError};
-use crate::state::{MarketConfig, MarketState, PlatformConfig, Position};
-
-pub fn calculate_reward(
-    prediction: i64,
-    resolution: i64,
-    decay: u64,
-    total_pool: u64,
-    total_scores: u128,
-) -> Result<u64> {
-    require!(decay > 0, PositionError::InvalidDecay);
-    if total_scores <= 0 {
-        return Ok(0);
-    };
-
-    let dist = (prediction - resolution).abs() as i128;
-    let decay_float = (DECAY_NORMALIZATION_FACTOR as f64 * decay as f64) / FIXED_POINT_SCALE as f64;
-    let exponent = -((dist as f64 / decay_float as f64).powi(2));
-    let score = (exponent.exp() * (FIXED_POINT_SCALE as f64)) as u128;
-    let reward = ((score
-        .checked_mul(total_pool as u128)
-        .ok_or(MarketError::MathOverflow)?)
-        / total_scores) as u64;
-
-    Ok(reward)
-}
+use crate::events::RewardClaimed;
+use crate::scoring::calculate_reward;
+use crate::state::{
+    MarketConfig, MarketState, MarketStatus, PlatformConfig, Position, SettlementMode,
+};
+use crate::token::{required, transfer_tokens, vault_token_accounts};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimReward<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, platform_config.admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
     #[account(
-        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         bump = market_config.bump,
     )]
     pub market_config: Account<'info, MarketConfig>,
 
     #[account(
         mut,
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         bump = market_state.bump,
     )]
     pub market_state: Account<'info, MarketState>,
@@ -66,55 +48,47 @@ pub struct ClaimReward<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ClaimReward<'info> {
-    pub fn claim_reward(&mut self) -> Result<()> {
-        require!(
-            self.market_state.is_approved,
-            MarketError::MarketNotApproved
-        );
+    pub fn claim_reward(&mut self) -> Result<RewardClaimed> {
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[MarketStatus::Settled],
+        )?;
 
-        require!(
-            self.market_state.is_resolved,
-            MarketError::MarketNotResolved
-        );
+        require!(!self.position.claimed, PositionError::RewardAlreadyClaimed);
 
-        let now = Clock::get()?.unix_timestamp;
         require!(
-            now >= self.market_config.end_time,
-            MarketError::MarketNotEnded
+            self.market_config.settlement_mode == SettlementMode::OnChain,
+            MarketError::InvalidSettlementMode
         );
 
-        require!(!self.position.claimed, PositionError::RewardAlreadyClaimed);
-
-        let resolution = self
-            .market_state
-            .resolution
-            .ok_or(MarketError::MarketNotResolved)?;
-
-        let total_scores = self
-            .market_state
-            .total_scores
-            .ok_or(MarketError::MarketNotResolved)?;
+        let score = self
+            .position
+            .score
+            .ok_or(PositionError::PositionNotSettled)?;
 
         let reward = calculate_reward(
-            self.position.prediction,
-            resolution,
-            self.position.decay,
+            score,
             self.market_state.total_pool,
-            total_scores,
+            self.market_state.total_scores,
         )?;
 
         self.position.reward = Some(reward);
 
-        let cpi_program = self.system_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.market_vault.to_account_info(),
-            to: self.user.to_account_info(),
-        };
-
         let seeds = &[
             MARKET_VAULT_SEED,
             self.market_config.to_account_info().key.as_ref(),
@@ -122,11 +96,47 @@ impl<'info> ClaimReward<'info> {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        transfer(cpi_ctx, reward)?;
+        match self.market_config.stake_mint {
+            None => {
+                let cpi_program = self.system_program.to_account_info();
+                let cpi_accounts = Transfer {
+                    from: self.market_vault.to_account_info(),
+                    to: self.user.to_account_info(),
+                };
+
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                transfer(cpi_ctx, reward)?;
+            }
+            Some(_) => {
+                let vault = vault_token_accounts(
+                    self.market_config.stake_mint,
+                    &self.market_vault.key(),
+                    &self.stake_mint,
+                    &self.vault_token_account,
+                    &self.token_program,
+                )?;
+
+                transfer_tokens(
+                    vault.vault_token_account,
+                    required(&self.user_token_account)?,
+                    self.market_vault.to_account_info(),
+                    vault.stake_mint,
+                    vault.token_program,
+                    reward,
+                    signer_seeds,
+                )?;
+            }
+        }
 
         self.position.claimed = true;
-
-        Ok(())
+        self.market_state.has_claims = true;
+
+        Ok(RewardClaimed {
+            market: self.market_config.key(),
+            position: self.position.key(),
+            user: self.user.key(),
+            score: Some(score),
+            reward,
+        })
     }
 }
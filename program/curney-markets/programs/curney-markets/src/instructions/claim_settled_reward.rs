@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::bond::locked_bonds;
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED, POSITION_SEED,
+};
+use crate::error::{MarketError, PositionError};
+use crate::events::RewardClaimed;
+use crate::merkle::{settlement_leaf, verify_proof};
+use crate::state::{
+    MarketConfig, MarketState, MarketStatus, PlatformConfig, Position, SettlementMode,
+};
+use crate::token::{required, transfer_tokens, vault_token_accounts};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimSettledReward<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, position.index.to_le_bytes().as_ref(), user.key().as_ref(), market_config.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimSettledReward<'info> {
+    pub fn claim_settled_reward(
+        &mut self,
+        reward: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<RewardClaimed> {
+        require!(
+            self.market_config.settlement_mode == SettlementMode::MerkleRoot,
+            MarketError::InvalidSettlementMode
+        );
+
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[MarketStatus::Settled],
+        )?;
+
+        require!(!self.position.claimed, PositionError::RewardAlreadyClaimed);
+
+        let root = self
+            .market_state
+            .settlement_root
+            .ok_or(MarketError::SettlementRootNotPosted)?;
+
+        let leaf = settlement_leaf(&self.position.key(), reward);
+        require!(
+            verify_proof(&root, leaf, &proof),
+            PositionError::InvalidProof
+        );
+
+        let claimed_payout = self
+            .market_state
+            .claimed_payout
+            .checked_add(reward)
+            .ok_or(MarketError::MathOverflow)?;
+
+        require!(
+            claimed_payout <= self.market_state.settlement_payout,
+            MarketError::PayoutExceedsPool
+        );
+
+        let vault_balance = match self.market_config.stake_mint {
+            None => self
+                .market_vault
+                .lamports()
+                .saturating_sub(locked_bonds(&self.market_config, &self.market_state)),
+            Some(_) => required(&self.vault_token_account)?.amount,
+        };
+
+        // Creator and platform fee revenue share the vault and must stay untouched
+        let available = vault_balance
+            .saturating_sub(self.market_state.creator_fee_revenue)
+            .saturating_sub(self.market_state.platform_fee_revenue);
+        require!(reward <= available, MarketError::InsufficientVaultBalance);
+
+        self.position.reward = Some(reward);
+
+        let seeds = &[
+            MARKET_VAULT_SEED,
+            self.market_config.to_account_info().key.as_ref(),
+            &[self.market_config.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        match self.market_config.stake_mint {
+            None => {
+                let cpi_program = self.system_program.to_account_info();
+                let cpi_accounts = Transfer {
+                    from: self.market_vault.to_account_info(),
+                    to: self.user.to_account_info(),
+                };
+
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                transfer(cpi_ctx, reward)?;
+            }
+            Some(_) => {
+                let vault = vault_token_accounts(
+                    self.market_config.stake_mint,
+                    &self.market_vault.key(),
+                    &self.stake_mint,
+                    &self.vault_token_account,
+                    &self.token_program,
+                )?;
+
+                transfer_tokens(
+                    vault.vault_token_account,
+                    required(&self.user_token_account)?,
+                    self.market_vault.to_account_info(),
+                    vault.stake_mint,
+                    vault.token_program,
+                    reward,
+                    signer_seeds,
+                )?;
+            }
+        }
+
+        self.market_state.claimed_payout = claimed_payout;
+        self.position.claimed = true;
+        self.market_state.has_claims = true;
+
+        Ok(RewardClaimed {
+            market: self.market_config.key(),
+            position: self.position.key(),
+            user: self.user.key(),
+            score: None,
+            reward,
+        })
+    }
+}
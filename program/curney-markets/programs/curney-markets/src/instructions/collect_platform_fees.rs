@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::bond::pay_from_vault;
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED,
+    PLATFORM_TREASURY_SEED,
+};
+use crate::error::{MarketError, PlatformError};
+use crate::events::PlatformFeesCollected;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+use crate::token::{
+    require_associated_token_account, required, transfer_tokens, vault_token_accounts,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CollectPlatformFees<'info> {
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump = platform_config.treasury_bump)]
+    pub platform_treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CollectPlatformFees<'info> {
+    /// Moves a market's escrowed platform fees into the treasury. Fees stay in the market
+    /// vault until it resolves, so a market voided with gross refunds never depends on the
+    /// treasury still holding them.
+    pub fn collect_platform_fees(&mut self) -> Result<PlatformFeesCollected> {
+        require!(
+            self.treasurer.key() == self.platform_config.treasurer,
+            PlatformError::Unauthorized
+        );
+
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[
+                MarketStatus::Resolved,
+                MarketStatus::Disputed,
+                MarketStatus::Settled,
+                MarketStatus::Voided,
+            ],
+        )?;
+
+        let amount = self.market_state.platform_fee_revenue;
+
+        require!(amount > 0, MarketError::NothingToWithdraw);
+
+        match self.market_config.stake_mint {
+            None => pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                self.platform_treasury.to_account_info(),
+                &self.system_program,
+                amount,
+            )?,
+            Some(_) => self.collect_tokens(amount)?,
+        }
+
+        self.market_state.platform_fee_revenue = 0;
+
+        Ok(PlatformFeesCollected {
+            market: self.market_config.key(),
+            treasurer: self.treasurer.key(),
+            amount,
+        })
+    }
+
+    fn collect_tokens(&self, amount: u64) -> Result<()> {
+        let vault = vault_token_accounts(
+            self.market_config.stake_mint,
+            &self.market_vault.key(),
+            &self.stake_mint,
+            &self.vault_token_account,
+            &self.token_program,
+        )?;
+        let treasury_token_account = required(&self.treasury_token_account)?;
+
+        require_associated_token_account(
+            treasury_token_account,
+            &self.platform_treasury.key(),
+            vault.stake_mint,
+            vault.token_program,
+        )?;
+
+        let seeds = &[
+            MARKET_VAULT_SEED,
+            self.market_config.to_account_info().key.as_ref(),
+            &[self.market_config.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer_tokens(
+            vault.vault_token_account,
+            treasury_token_account,
+            self.market_vault.to_account_info(),
+            vault.stake_mint,
+            vault.token_program,
+            amount,
+            signer_seeds,
+        )
+    }
+}
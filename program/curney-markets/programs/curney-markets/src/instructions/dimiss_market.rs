@@ -1,24 +1,30 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+use crate::bond::pay_from_vault;
 use crate::constants::{
-    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED,
-    PLATFORM_TREASURY_SEED,
+    BASIS_POINT_SCALE, MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_TOMBSTONE_SEED,
+    MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED,
 };
 use crate::error::MarketError;
-use crate::state::{MarketConfig, MarketState, PlatformConfig};
+use crate::events::MarketDismissed;
+use crate::state::{
+    DismissReason, MarketConfig, MarketState, MarketStatus, MarketTombstone, PlatformConfig,
+};
+use crate::token::{sweep_and_close_token_account, vault_token_accounts};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DismissMarket<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub approver: Signer<'info>,
 
     /// CHECK: This is the market creator and is validated in the instruction handler
     #[account(mut)]
     pub creator: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
@@ -29,7 +35,7 @@ pub struct DismissMarket<'info> {
     #[account(
         mut,
         close = creator,
-        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         bump = market_config.bump,
     )]
     pub market_config: Account<'info, MarketConfig>,
@@ -37,7 +43,7 @@ pub struct DismissMarket<'info> {
     #[account(
         mut,
         close = creator,
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         bump = market_state.bump,
     )]
     pub market_state: Account<'info, MarketState>,
@@ -45,60 +51,122 @@ pub struct DismissMarket<'info> {
     #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
     pub market_vault: SystemAccount<'info>,
 
+    #[account(
+        init,
+        payer = approver,
+        seeds = [MARKET_TOMBSTONE_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        space = 8 + MarketTombstone::INIT_SPACE,
+        bump,
+    )]
+    pub market_tombstone: Account<'info, MarketTombstone>,
+
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives any tokens sent to the vault, only required when it holds a balance
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> DismissMarket<'info> {
-    pub fn dismiss_market(&mut self) -> Result<()> {
+    /// Closes the market accounts with their rent going back to the creator, and leaves a
+    /// tombstone behind so the market ID cannot be proposed again. The escrowed proposal fee
+    /// is refunded at the platform's dismissal rate, or its spam rate for spam.
+    pub fn dismiss_market(
+        &mut self,
+        reason: DismissReason,
+        bumps: &DismissMarketBumps,
+    ) -> Result<MarketDismissed> {
         require!(
-            self.admin.key() == self.platform_config.admin,
+            self.approver.key() == self.platform_config.approver,
             MarketError::Unauthorized
         );
 
-        require!(
-            !self.market_state.is_approved,
-            MarketError::MarketAlreadyApproved
-        );
-
-        require!(
-            !self.market_state.is_resolved,
-            MarketError::MarketAlreadyResolved
-        );
-
         require!(
             self.creator.key() == self.market_config.creator,
             MarketError::InvalidCreator
         );
 
-        // Refund the creator half the market proposal fee
-        let cpi_program = self.system_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.platform_treasury.to_account_info(),
-            to: self.creator.to_account_info(),
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Dismissed)?;
+
+        self.market_tombstone.set_inner(MarketTombstone {
+            bump: bumps.market_tombstone,
+            market_id: self.market_config.market_id,
+            dismissed_at: now,
+            reason,
+            creator: self.market_config.creator,
+        });
+
+        // Keep the unrefunded share of the escrowed proposal fee, the refund goes back with the
+        // vault below
+        let refund_bps = match reason {
+            DismissReason::Spam => self.platform_config.spam_dismissal_refund_bps,
+            _ => self.platform_config.dismissal_refund_bps,
         };
-
-        let seeds = &[
-            PLATFORM_TREASURY_SEED,
-            self.platform_config.to_account_info().key.as_ref(),
-            &[self.platform_config.treasury_bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        transfer(cpi_ctx, self.platform_config.market_proposal_fee / 2)?;
-
-        // Empty the market vault and close the account
-        let rent = self.market_vault.to_account_info().lamports();
-        self.creator.add_lamports(rent)?;
-        self.market_vault.sub_lamports(rent)?;
-        let mut data = self.market_vault.try_borrow_mut_data()?;
-        for byte in data.iter_mut() {
-            *byte = 0;
+        let proposal_fee = self.market_config.proposal_fee;
+        let proposal_fee_refund = proposal_fee
+            .checked_mul(refund_bps as u64)
+            .ok_or(MarketError::MathOverflow)?
+            / BASIS_POINT_SCALE as u64;
+
+        pay_from_vault(
+            &self.market_config,
+            &self.market_vault,
+            self.platform_treasury.to_account_info(),
+            &self.system_program,
+            proposal_fee - proposal_fee_refund,
+        )?;
+
+        // Close the vault token account of a token market, sweeping any balance to the creator
+        if self.market_config.stake_mint.is_some() {
+            let vault = vault_token_accounts(
+                self.market_config.stake_mint,
+                &self.market_vault.key(),
+                &self.stake_mint,
+                &self.vault_token_account,
+                &self.token_program,
+            )?;
+
+            let seeds = &[
+                MARKET_VAULT_SEED,
+                self.market_config.to_account_info().key.as_ref(),
+                &[self.market_config.vault_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            sweep_and_close_token_account(
+                &vault,
+                &self.creator_token_account,
+                &self.creator.key(),
+                self.creator.to_account_info(),
+                self.market_vault.to_account_info(),
+                signer_seeds,
+            )?;
         }
-        self.market_vault
-            .to_account_info()
-            .assign(&self.system_program.key());
 
-        Ok(())
+        // The vault is owned by the system program, so it is emptied with a signed transfer
+        pay_from_vault(
+            &self.market_config,
+            &self.market_vault,
+            self.creator.to_account_info(),
+            &self.system_program,
+            self.market_vault.lamports(),
+        )?;
+
+        Ok(MarketDismissed {
+            market: self.market_config.key(),
+            market_id: self.market_config.market_id,
+            creator: self.creator.key(),
+            reason,
+            proposal_fee_refund,
+        })
     }
 }
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::{DISPUTE_SEED, MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::{DisputeError, MarketError};
+use crate::events::DisputeOpened;
+use crate::state::{Dispute, MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DisputeResolution<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(
+        init,
+        payer = challenger,
+        seeds = [DISPUTE_SEED, market_config.key().as_ref(), challenger.key().as_ref()],
+        space = 8 + Dispute::INIT_SPACE,
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DisputeResolution<'info> {
+    /// Challenges the resolved value with an alternative, escrowing the platform's dispute
+    /// bond in the dispute account until the arbiter settles it.
+    pub fn dispute_resolution(
+        &mut self,
+        proposed_resolution: i64,
+        bumps: &DisputeResolutionBumps,
+    ) -> Result<DisputeOpened> {
+        let now = Clock::get()?.unix_timestamp;
+        let status = self.market_state.require_status(
+            &self.market_config,
+            now,
+            &[MarketStatus::Resolved, MarketStatus::Disputed],
+        )?;
+
+        require!(
+            now < self.market_state.dispute_ends_at,
+            DisputeError::DisputeWindowClosed
+        );
+
+        require!(
+            self.market_state.resolution != Some(proposed_resolution),
+            DisputeError::SameResolution
+        );
+
+        let bond = self.platform_config.dispute_bond;
+
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.challenger.to_account_info(),
+            to: self.dispute.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer(cpi_ctx, bond)?;
+
+        self.dispute.set_inner(Dispute {
+            bump: bumps.dispute,
+            bond,
+            proposed_resolution,
+            challenger: self.challenger.key(),
+            market: self.market_config.key(),
+        });
+
+        self.market_state.open_disputes = self
+            .market_state
+            .open_disputes
+            .checked_add(1)
+            .ok_or(MarketError::MathOverflow)?;
+
+        if status == MarketStatus::Resolved {
+            self.market_state
+                .transition(&self.market_config, now, MarketStatus::Disputed)?;
+        }
+
+        Ok(DisputeOpened {
+            market: self.market_config.key(),
+            dispute: self.dispute.key(),
+            challenger: self.challenger.key(),
+            proposed_resolution,
+            bond,
+            open_disputes: self.market_state.open_disputes,
+        })
+    }
+}
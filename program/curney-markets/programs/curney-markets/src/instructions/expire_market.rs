@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::MarketError;
+use crate::events::MarketVoided;
+use crate::state::{FeeRefundPolicy, MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExpireMarket<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExpireMarket<'info> {
+    /// Voids a market that missed its resolution deadline. A resolved market whose
+    /// settlement root is never posted, or whose dispute is never ruled on, expires once the
+    /// settlement grace period past its dispute window has run out.
+    pub fn expire_market(&mut self) -> Result<MarketVoided> {
+        let now = Clock::get()?.unix_timestamp;
+        let status = self.market_state.status_at(&self.market_config, now);
+
+        let refund_policy = match status {
+            MarketStatus::Resolved | MarketStatus::Disputed => {
+                require!(
+                    now >= self.market_state.settlement_deadline(&self.platform_config),
+                    MarketError::SettlementGracePeriod
+                );
+
+                // The creator and the platform may already hold their fees, so only the net
+                // stakes go back to the users
+                FeeRefundPolicy::StakeOnly
+            }
+            _ => {
+                require!(
+                    now >= self.market_config.resolution_deadline,
+                    MarketError::ResolutionDeadlineNotReached
+                );
+
+                // Stakes and creator fees go back to the users, while the escrowed platform
+                // fees stay collectable by the platform
+                self.market_state.creator_fee_revenue = 0;
+                FeeRefundPolicy::StakeAndCreatorFee
+            }
+        };
+
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Voided)?;
+        self.market_state.refund_policy = Some(refund_policy);
+        self.market_state.is_expired = true;
+
+        Ok(MarketVoided {
+            market: self.market_config.key(),
+            refund_policy,
+            is_expired: true,
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::bond::pay_from_vault;
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED,
+};
+use crate::error::{BondError, MarketError};
+use crate::events::MarketResolved;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeResolution<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: This is the market creator and is validated in the instruction handler
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FinalizeResolution<'info> {
+    /// Resolves a bonded market to its unchallenged proposal once the liveness period is
+    /// over, and returns the creator bond. Anyone can call this.
+    pub fn finalize_resolution(&mut self) -> Result<MarketResolved> {
+        require!(
+            self.creator.key() == self.market_config.creator,
+            MarketError::InvalidCreator
+        );
+
+        let resolution = self
+            .market_state
+            .proposed_resolution
+            .ok_or(BondError::ResolutionNotProposed)?;
+
+        require!(
+            self.market_state.challenger.is_none(),
+            BondError::AlreadyChallenged
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Closed])?;
+
+        require!(
+            now >= self.market_state.proposal_ends_at,
+            BondError::LivenessPeriodNotEnded
+        );
+
+        pay_from_vault(
+            &self.market_config,
+            &self.market_vault,
+            self.creator.to_account_info(),
+            &self.system_program,
+            self.market_config.creator_bond,
+        )?;
+        self.market_state.creator_bond_locked = false;
+
+        // The liveness period already served as the dispute window
+        self.market_state.dispute_ends_at = now;
+        self.market_state.resolution = Some(resolution);
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Resolved)?;
+
+        Ok(MarketResolved {
+            market: self.market_config.key(),
+            resolution,
+            dispute_ends_at: now,
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PLATFORM_CONFIG_SEED;
+use crate::error::PlatformError;
+use crate::events::RoleGranted;
+use crate::state::{PlatformConfig, PlatformRole};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl<'info> GrantRole<'info> {
+    /// Hands `role` to `holder`, taking it from its current holder.
+    pub fn grant_role(&mut self, role: PlatformRole, holder: Pubkey) -> Result<RoleGranted> {
+        require!(
+            self.admin.key() == self.platform_config.admin,
+            PlatformError::Unauthorized
+        );
+
+        let previous_holder = self.platform_config.role_holder(role);
+        self.platform_config.set_role_holder(role, holder);
+
+        Ok(RoleGranted {
+            platform_config: self.platform_config.key(),
+            role,
+            previous_holder,
+            holder,
+        })
+    }
+}
@@ -2,9 +2,12 @@ use anchor_lang::prelude::*;
 
 use crate::constants::{BASIS_POINT_SCALE, PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED};
 use crate::error::PlatformError;
+use crate::events::PlatformInitialized;
 use crate::state::PlatformConfig;
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(platform_id: u64)]
 pub struct InitializePlatform<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -12,7 +15,7 @@ pub struct InitializePlatform<'info> {
     #[account(
         init,
         payer = admin,
-        seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_id.to_le_bytes().as_ref()],
         space = 8 + PlatformConfig::INIT_SPACE,
         bump,
     )]
@@ -25,13 +28,23 @@ pub struct InitializePlatform<'info> {
 }
 
 impl<'info> InitializePlatform<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_platform(
         &mut self,
+        platform_id: u64,
         creator_fee_bps: u16,
         platform_fee_bps: u16,
         market_proposal_fee: u64,
+        dismissal_refund_bps: u16,
+        spam_dismissal_refund_bps: u16,
+        withdrawal_refund_bps: u16,
+        dispute_period: i64,
+        dispute_bond: u64,
+        dispute_reward_bps: u16,
+        resolver_grace_period: i64,
+        settlement_grace_period: i64,
         bumps: &InitializePlatformBumps,
-    ) -> Result<()> {
+    ) -> Result<PlatformInitialized> {
         require!(
             creator_fee_bps <= BASIS_POINT_SCALE,
             PlatformError::InvalidCreatorFeeBps
@@ -52,14 +65,76 @@ impl<'info> InitializePlatform<'info> {
             PlatformError::InvalidMarketProposalFee
         );
 
+        require!(
+            dismissal_refund_bps <= BASIS_POINT_SCALE
+                && spam_dismissal_refund_bps <= BASIS_POINT_SCALE,
+            PlatformError::InvalidDismissalRefundBps
+        );
+
+        require!(
+            withdrawal_refund_bps <= BASIS_POINT_SCALE,
+            PlatformError::InvalidWithdrawalRefundBps
+        );
+
+        require!(dispute_period >= 0, PlatformError::InvalidDisputePeriod);
+
+        require!(dispute_bond > 0, PlatformError::InvalidDisputeBond);
+
+        require!(
+            dispute_reward_bps <= BASIS_POINT_SCALE,
+            PlatformError::InvalidDisputeRewardBps
+        );
+
+        require!(
+            resolver_grace_period >= 0,
+            PlatformError::InvalidResolverGracePeriod
+        );
+
+        require!(
+            settlement_grace_period >= 0,
+            PlatformError::InvalidSettlementGracePeriod
+        );
+
         self.platform_config.set_inner(PlatformConfig {
             bump: bumps.platform_config,
             treasury_bump: bumps.platform_treasury,
+            platform_id,
             creator_fee_bps,
             platform_fee_bps,
             market_proposal_fee,
+            dismissal_refund_bps,
+            spam_dismissal_refund_bps,
+            withdrawal_refund_bps,
+            dispute_period,
+            dispute_bond,
+            dispute_reward_bps,
+            resolver_grace_period,
+            settlement_grace_period,
             admin: self.admin.key(),
+            pending_admin: None,
+            approver: self.admin.key(),
+            resolver: self.admin.key(),
+            treasurer: self.admin.key(),
+            pauser: self.admin.key(),
+            arbiter: self.admin.key(),
+            is_paused: false,
+            namespace: self.platform_config.key(),
         });
-        Ok(())
+        Ok(PlatformInitialized {
+            platform_config: self.platform_config.key(),
+            platform_id,
+            admin: self.admin.key(),
+            creator_fee_bps,
+            platform_fee_bps,
+            market_proposal_fee,
+            dismissal_refund_bps,
+            spam_dismissal_refund_bps,
+            withdrawal_refund_bps,
+            dispute_period,
+            dispute_bond,
+            dispute_reward_bps,
+            resolver_grace_period,
+            settlement_grace_period,
+        })
     }
 }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MARKET_CONFIG_SEED, PLATFORM_CONFIG_SEED, PRICE_SAMPLES_SEED};
+use crate::error::OracleError;
+use crate::events::PriceSamplesInitialized;
+use crate::state::{MarketConfig, PlatformConfig, PriceSamples};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializePriceSamples<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [PRICE_SAMPLES_SEED, market_config.key().as_ref()],
+        space = 8 + PriceSamples::INIT_SPACE,
+        bump,
+    )]
+    pub price_samples: Account<'info, PriceSamples>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializePriceSamples<'info> {
+    pub fn initialize_price_samples(
+        &mut self,
+        bumps: &InitializePriceSamplesBumps,
+    ) -> Result<PriceSamplesInitialized> {
+        require!(
+            self.market_config
+                .oracle
+                .is_some_and(|oracle| oracle.sampling.is_some()),
+            OracleError::SamplingNotEnabled
+        );
+
+        self.price_samples.set_inner(PriceSamples {
+            bump: bumps.price_samples,
+            next_index: 0,
+            last_publish_time: i64::MIN,
+            market: self.market_config.key(),
+            samples: Vec::new(),
+        });
+
+        Ok(PriceSamplesInitialized {
+            market: self.market_config.key(),
+            payer: self.payer.key(),
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::committee::validate_committee;
+use crate::constants::{PLATFORM_CONFIG_SEED, RESOLVER_COMMITTEE_SEED};
+use crate::error::PlatformError;
+use crate::events::ResolverCommitteeUpdated;
+use crate::state::{PlatformConfig, ResolverCommittee};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeResolverCommittee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [RESOLVER_COMMITTEE_SEED, platform_config.namespace.as_ref()],
+        space = 8 + ResolverCommittee::INIT_SPACE,
+        bump,
+    )]
+    pub resolver_committee: Account<'info, ResolverCommittee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeResolverCommittee<'info> {
+    pub fn initialize_resolver_committee(
+        &mut self,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        tolerance: u64,
+        bumps: &InitializeResolverCommitteeBumps,
+    ) -> Result<ResolverCommitteeUpdated> {
+        require!(
+            self.admin.key() == self.platform_config.admin,
+            PlatformError::Unauthorized
+        );
+
+        validate_committee(&members, threshold)?;
+
+        self.resolver_committee.set_inner(ResolverCommittee {
+            bump: bumps.resolver_committee,
+            threshold,
+            tolerance,
+            flag_counts: vec![0; members.len()],
+            members,
+        });
+
+        Ok(ResolverCommitteeUpdated {
+            platform_config: self.platform_config.key(),
+            members: self.resolver_committee.members.clone(),
+            threshold: self.resolver_committee.threshold,
+            tolerance: self.resolver_committee.tolerance,
+        })
+    }
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::{
+    DEFAULT_SETTLEMENT_GRACE_PERIOD, PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED,
+};
+use crate::error::PlatformError;
+use crate::events::PlatformMigrated;
+use crate::state::{LegacyPlatformConfig, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(platform_id: u64)]
+pub struct MigratePlatform<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: This is the admin-keyed platform config, which is validated and closed in the instruction handler
+    #[account(mut, seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()], bump)]
+    pub legacy_platform_config: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, legacy_platform_config.key().as_ref()], bump)]
+    pub legacy_platform_treasury: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [PLATFORM_CONFIG_SEED, platform_id.to_le_bytes().as_ref()],
+        space = 8 + PlatformConfig::INIT_SPACE,
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump)]
+    pub platform_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigratePlatform<'info> {
+    /// Moves a platform config addressed by its admin key to one addressed by `platform_id`,
+    /// moves the treasury balance to the new config's treasury, and closes the old config.
+    ///
+    /// Markets are not migrated. Markets in the admin-keyed layout cannot be read by this
+    /// program version, so every one of them has to be claimed out, dismissed or refunded with
+    /// the previous version before the upgrade; nothing on chain counts them, so this cannot be
+    /// checked here. The new config keeps the old config's address as its `namespace`, so
+    /// market IDs used before the migration stay taken. Settings the old layout did not
+    /// have start out matching its behaviour, with no proposal fee refunds and no dispute
+    /// period, stalled settlements get a week before they expire, and every role goes to the
+    /// admin.
+    pub fn migrate_platform(
+        &mut self,
+        platform_id: u64,
+        bumps: &MigratePlatformBumps,
+    ) -> Result<PlatformMigrated> {
+        require_keys_eq!(
+            *self.legacy_platform_config.owner,
+            crate::ID,
+            PlatformError::InvalidLegacyPlatformConfig
+        );
+
+        let legacy = {
+            let data = self.legacy_platform_config.try_borrow_data()?;
+            require!(
+                data.starts_with(PlatformConfig::DISCRIMINATOR),
+                PlatformError::InvalidLegacyPlatformConfig
+            );
+            LegacyPlatformConfig::deserialize(&mut &data[PlatformConfig::DISCRIMINATOR.len()..])
+                .map_err(|_| error!(PlatformError::InvalidLegacyPlatformConfig))?
+        };
+
+        require!(
+            legacy.admin == self.admin.key(),
+            PlatformError::Unauthorized
+        );
+
+        self.platform_config.set_inner(PlatformConfig {
+            bump: bumps.platform_config,
+            treasury_bump: bumps.platform_treasury,
+            platform_id,
+            creator_fee_bps: legacy.creator_fee_bps,
+            platform_fee_bps: legacy.platform_fee_bps,
+            market_proposal_fee: legacy.market_proposal_fee,
+            dismissal_refund_bps: 0,
+            spam_dismissal_refund_bps: 0,
+            withdrawal_refund_bps: 0,
+            dispute_period: 0,
+            // Unused while the dispute period is zero, but kept positive like any other config
+            dispute_bond: legacy.market_proposal_fee,
+            dispute_reward_bps: 0,
+            resolver_grace_period: 0,
+            settlement_grace_period: DEFAULT_SETTLEMENT_GRACE_PERIOD,
+            admin: legacy.admin,
+            pending_admin: None,
+            approver: legacy.admin,
+            resolver: legacy.admin,
+            treasurer: legacy.admin,
+            pauser: legacy.admin,
+            arbiter: legacy.admin,
+            is_paused: false,
+            namespace: self.legacy_platform_config.key(),
+        });
+
+        // Move the fees collected so far to the treasury of the new config
+        let treasury_balance = self.legacy_platform_treasury.lamports();
+        if treasury_balance > 0 {
+            let legacy_key = self.legacy_platform_config.key();
+            let seeds = &[
+                PLATFORM_TREASURY_SEED,
+                legacy_key.as_ref(),
+                &[bumps.legacy_platform_treasury],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: self.legacy_platform_treasury.to_account_info(),
+                to: self.platform_treasury.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            transfer(cpi_ctx, treasury_balance)?;
+        }
+
+        // Close the legacy config, returning its rent to the admin
+        let rent = self.legacy_platform_config.lamports();
+        self.legacy_platform_config.sub_lamports(rent)?;
+        self.admin.add_lamports(rent)?;
+
+        let legacy_info = self.legacy_platform_config.to_account_info();
+        legacy_info.assign(&self.system_program.key());
+        legacy_info.resize(0)?;
+
+        Ok(PlatformMigrated {
+            platform_config: self.platform_config.key(),
+            legacy_platform_config: self.legacy_platform_config.key(),
+            platform_id,
+            admin: self.admin.key(),
+            treasury_balance,
+        })
+    }
+}
@@ -1,23 +1,79 @@
+pub mod accept_admin;
+pub mod amend_resolution;
 pub mod approve_market;
+pub mod challenge_resolution;
 pub mod claim_reward;
+pub mod claim_settled_reward;
+pub mod collect_platform_fees;
 pub mod dimiss_market;
+pub mod dispute_resolution;
+pub mod expire_market;
+pub mod finalize_resolution;
+pub mod grant_role;
 pub mod initialize_platform;
+pub mod initialize_price_samples;
+pub mod initialize_resolver_committee;
+pub mod migrate_platform;
 pub mod place_prediction;
+pub mod post_settlement_root;
+pub mod propose_admin;
 pub mod propose_market;
+pub mod propose_resolution;
+pub mod push_price_sample;
+pub mod refund_position;
+pub mod resolve_from_oracle;
 pub mod resolve_market;
+pub mod return_bonds;
+pub mod return_dispute_bond;
+pub mod revoke_role;
+pub mod set_paused;
+pub mod settle_dispute;
+pub mod settle_positions;
+pub mod settle_resolution_challenge;
 pub mod update_market_config;
 pub mod update_platform_config;
+pub mod update_resolver_committee;
+pub mod void_market;
 pub mod withdraw_creator_revenue;
 pub mod withdraw_platform_fees;
+pub mod withdraw_proposal;
 
+pub use accept_admin::*;
+pub use amend_resolution::*;
 pub use approve_market::*;
+pub use challenge_resolution::*;
 pub use claim_reward::*;
+pub use claim_settled_reward::*;
+pub use collect_platform_fees::*;
 pub use dimiss_market::*;
+pub use dispute_resolution::*;
+pub use expire_market::*;
+pub use finalize_resolution::*;
+pub use grant_role::*;
 pub use initialize_platform::*;
+pub use initialize_price_samples::*;
+pub use initialize_resolver_committee::*;
+pub use migrate_platform::*;
 pub use place_prediction::*;
+pub use post_settlement_root::*;
+pub use propose_admin::*;
 pub use propose_market::*;
+pub use propose_resolution::*;
+pub use push_price_sample::*;
+pub use refund_position::*;
+pub use resolve_from_oracle::*;
 pub use resolve_market::*;
+pub use return_bonds::*;
+pub use return_dispute_bond::*;
+pub use revoke_role::*;
+pub use set_paused::*;
+pub use settle_dispute::*;
+pub use settle_positions::*;
+pub use settle_resolution_challenge::*;
 pub use update_market_config::*;
 pub use update_platform_config::*;
+pub use update_resolver_committee::*;
+pub use void_market::*;
 pub use withdraw_creator_revenue::*;
 pub use withdraw_platform_fees::*;
+pub use withdraw_proposal::*;
@@ -1,54 +1,40 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::{
-    FIXED_POINT_SCALE, MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED,
-    PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED, POSITION_SEED,
+    BASIS_POINT_SCALE, MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED,
+    PLATFORM_CONFIG_SEED, POSITION_SEED,
+};
+use crate::error::{MarketError, PlatformError};
+use crate::events::PredictionPlaced;
+use crate::scoring::calculate_sigma;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig, Position};
+use crate::token::{
+    require_associated_token_account, require_stake_mint, required, transfer_tokens_received,
 };
-use crate::error::MarketError;
-use crate::state::{MarketConfig, MarketState, PlatformConfig, Position};
-
-fn calculate_new_decay(old_decay: u64, start_time: i64, end_time: i64, now: i64) -> Result<u64> {
-    let duration = (end_time - start_time) as u64;
-    require!(duration > 0, MarketError::InvalidEndTime);
-
-    let elapsed = (now - start_time).max(0) as u64;
-    let progress = (elapsed * FIXED_POINT_SCALE) / duration;
-    let remaining = FIXED_POINT_SCALE
-        .checked_sub(progress)
-        .ok_or(MarketError::MathOverflow)?;
-
-    let new_decay = (old_decay as u128)
-        .checked_mul(remaining as u128)
-        .ok_or(MarketError::MathOverflow)?
-        / (FIXED_POINT_SCALE as u128);
-
-    Ok(new_decay.max(0) as u64)
-}
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PlacePrediction<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, platform_config.admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump = platform_config.treasury_bump)]
-    pub platform_treasury: SystemAccount<'info>,
-
     #[account(
-        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         bump = market_config.bump,
     )]
     pub market_config: Account<'info, MarketConfig>,
 
     #[account(
         mut,
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         bump = market_state.bump,
     )]
     pub market_state: Account<'info, MarketState>,
@@ -65,6 +51,16 @@ pub struct PlacePrediction<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -74,15 +70,10 @@ impl<'info> PlacePrediction<'info> {
         prediction: i64,
         stake_amount: u64,
         bumps: &PlacePredictionBumps,
-    ) -> Result<()> {
+    ) -> Result<PredictionPlaced> {
         require!(
-            self.market_state.is_approved,
-            MarketError::MarketNotApproved
-        );
-
-        require!(
-            !self.market_state.is_resolved,
-            MarketError::MarketAlreadyResolved
+            !self.platform_config.is_paused,
+            PlatformError::PlatformPaused
         );
 
         require!(
@@ -92,48 +83,36 @@ impl<'info> PlacePrediction<'info> {
 
         let now = Clock::get()?.unix_timestamp;
 
-        require!(
-            now >= self.market_config.start_time,
-            MarketError::MarketNotStarted
-        );
-
-        require!(now < self.market_config.end_time, MarketError::MarketEnded);
-
-        // Take platform fee
-        let platform_fee = (self.platform_config.platform_fee_bps as u64 * stake_amount) / 10000;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Trading])?;
 
-        let cpi_program = self.system_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.user.to_account_info(),
-            to: self.platform_treasury.to_account_info(),
+        let (platform_fee, creator_fee, actual_stake) = match self.market_config.stake_mint {
+            None => self.collect_lamport_stake(stake_amount)?,
+            Some(_) => self.collect_token_stake(stake_amount)?,
         };
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        transfer(cpi_ctx, platform_fee)?;
-
-        // Calculate creator fee and actual user stake amount
-        let creator_fee = (self.platform_config.creator_fee_bps as u64 * stake_amount) / 10000;
-
-        let actual_stake = stake_amount - platform_fee - creator_fee;
 
-        // Transfer both the user stake and the creator fee to the market vault
-        let cpi_program = self.system_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.user.to_account_info(),
-            to: self.market_vault.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        transfer(cpi_ctx, actual_stake + creator_fee)?;
+        let sigma = calculate_sigma(
+            self.market_config.sigma_min,
+            self.market_config.sigma_max,
+            self.market_config.start_time,
+            self.market_config.end_time,
+            now,
+        )?;
 
         self.position.set_inner(Position {
             bump: bumps.position,
             user: self.user.key(),
             market: self.market_config.key(),
-            decay: self.market_state.decay,
+            sigma,
             index: self.market_state.total_positions,
             timestamp: now,
             claimed: false,
+            score: None,
+            scored_amendment: 0,
             reward: None,
             stake: actual_stake,
+            platform_fee,
+            creator_fee,
             prediction,
         });
 
@@ -155,13 +134,95 @@ impl<'info> PlacePrediction<'info> {
             .checked_add(creator_fee)
             .ok_or(MarketError::MathOverflow)?;
 
-        self.market_state.decay = calculate_new_decay(
-            self.market_state.decay,
-            self.market_config.start_time,
-            self.market_config.end_time,
-            now,
+        self.market_state.platform_fee_revenue = self
+            .market_state
+            .platform_fee_revenue
+            .checked_add(platform_fee)
+            .ok_or(MarketError::MathOverflow)?;
+
+        Ok(PredictionPlaced {
+            market: self.market_config.key(),
+            position: self.position.key(),
+            user: self.user.key(),
+            index: self.position.index,
+            prediction,
+            gross_stake: stake_amount,
+            platform_fee,
+            creator_fee,
+            net_stake: actual_stake,
+            sigma,
+            timestamp: now,
+        })
+    }
+
+    /// Collects a SOL stake, returning the platform fee, the creator fee and the net stake.
+    /// Both fees stay escrowed in the market vault with the stake.
+    fn collect_lamport_stake(&self, stake_amount: u64) -> Result<(u64, u64, u64)> {
+        let (platform_fee, creator_fee, actual_stake) = self.split_fees(stake_amount)?;
+
+        // Transfer the whole stake to the market vault
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.market_vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer(cpi_ctx, stake_amount)?;
+
+        Ok((platform_fee, creator_fee, actual_stake))
+    }
+
+    /// Collects a token stake, returning the platform fee, the creator fee and the net stake.
+    /// Fees are taken from what actually reached the vault, after any Token-2022 transfer fee.
+    fn collect_token_stake(&mut self, stake_amount: u64) -> Result<(u64, u64, u64)> {
+        let stake_mint = required(&self.stake_mint)?;
+        let token_program = required(&self.token_program)?;
+        let user_token_account = required(&self.user_token_account)?;
+        let vault_token_account = self
+            .vault_token_account
+            .as_mut()
+            .ok_or(MarketError::MissingTokenAccount)?;
+
+        require_stake_mint(self.market_config.stake_mint, stake_mint)?;
+        require_associated_token_account(
+            vault_token_account,
+            &self.market_vault.key(),
+            stake_mint,
+            token_program,
+        )?;
+
+        // Transfer the whole stake to the market vault
+        let received = transfer_tokens_received(
+            user_token_account,
+            vault_token_account,
+            self.user.to_account_info(),
+            stake_mint,
+            token_program,
+            stake_amount,
         )?;
 
-        Ok(())
+        // Both fees stay escrowed in the vault
+        self.split_fees(received)
+    }
+
+    /// Splits an amount into the platform fee, the creator fee and the net stake.
+    fn split_fees(&self, amount: u64) -> Result<(u64, u64, u64)> {
+        let fee = |bps: u16| -> Result<u64> {
+            let fee = (amount as u128)
+                .checked_mul(bps as u128)
+                .ok_or(MarketError::MathOverflow)?
+                / BASIS_POINT_SCALE as u128;
+            u64::try_from(fee).map_err(|_| error!(MarketError::MathOverflow))
+        };
+
+        let platform_fee = fee(self.platform_config.platform_fee_bps)?;
+        let creator_fee = fee(self.platform_config.creator_fee_bps)?;
+
+        let actual_stake = amount
+            .checked_sub(platform_fee)
+            .and_then(|stake| stake.checked_sub(creator_fee))
+            .ok_or(MarketError::MathOverflow)?;
+
+        Ok((platform_fee, creator_fee, actual_stake))
     }
 }
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::MarketError;
+use crate::events::SettlementRootPosted;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig, SettlementMode};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PostSettlementRoot<'info> {
+    #[account(mut)]
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PostSettlementRoot<'info> {
+    pub fn post_settlement_root(
+        &mut self,
+        root: [u8; 32],
+        total_payout: u64,
+    ) -> Result<SettlementRootPosted> {
+        require!(
+            self.resolver.key() == self.platform_config.resolver,
+            MarketError::Unauthorized
+        );
+
+        require!(
+            self.market_config.settlement_mode == SettlementMode::MerkleRoot,
+            MarketError::InvalidSettlementMode
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Resolved])?;
+
+        require!(
+            now >= self.market_state.dispute_ends_at,
+            MarketError::ResolutionNotFinal
+        );
+
+        require!(
+            self.market_state.settlement_root.is_none(),
+            MarketError::SettlementRootAlreadyPosted
+        );
+
+        require!(
+            total_payout <= self.market_state.total_pool,
+            MarketError::PayoutExceedsPool
+        );
+
+        self.market_state.settlement_root = Some(root);
+        self.market_state.settlement_payout = total_payout;
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Settled)?;
+
+        Ok(SettlementRootPosted {
+            market: self.market_config.key(),
+            resolver: self.resolver.key(),
+            root,
+            total_payout,
+        })
+    }
+}
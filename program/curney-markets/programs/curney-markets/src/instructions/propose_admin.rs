@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PLATFORM_CONFIG_SEED;
+use crate::error::PlatformError;
+use crate::events::AdminProposed;
+use crate::state::PlatformConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    /// Starts handing the platform over to `pending_admin`, who takes over once they accept.
+    /// Passing `None` cancels a pending transfer.
+    pub fn propose_admin(&mut self, pending_admin: Option<Pubkey>) -> Result<AdminProposed> {
+        require!(
+            self.admin.key() == self.platform_config.admin,
+            PlatformError::Unauthorized
+        );
+
+        self.platform_config.pending_admin = pending_admin;
+
+        Ok(AdminProposed {
+            platform_config: self.platform_config.key(),
+            admin: self.admin.key(),
+            pending_admin,
+        })
+    }
+}
@@ -1,14 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::{
-    DECAY_DURATION_SCALE, FIXED_POINT_SCALE, MARKET_CONFIG_SEED, MARKET_DESCRIPTION_MAX_LEN,
-    MARKET_QUESTION_MAX_LEN, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED,
-    PLATFORM_TREASURY_SEED,
+    MARKET_CONFIG_SEED, MARKET_DESCRIPTION_MAX_LEN, MARKET_QUESTION_MAX_LEN, MARKET_STATE_SEED,
+    MARKET_TOMBSTONE_SEED, MARKET_VAULT_SEED, MAX_PRICE_SAMPLES, PLATFORM_CONFIG_SEED,
 };
-use crate::error::MarketError;
-use crate::state::{MarketConfig, MarketState, PlatformConfig};
+use crate::error::{MarketError, OracleError, PlatformError};
+use crate::events::MarketProposed;
+use crate::state::{
+    MarketConfig, MarketState, MarketStatus, PlatformConfig, PriceOracle, ScoringCurve,
+    SettlementMode, StakeWeighting,
+};
+use crate::token::required;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(market_id: u64)]
 pub struct ProposeMarket<'info> {
@@ -16,18 +23,15 @@ pub struct ProposeMarket<'info> {
     pub creator: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, platform_config.admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump = platform_config.treasury_bump)]
-    pub platform_treasury: SystemAccount<'info>,
-
     #[account(
         init,
         payer = creator,
-        seeds = [MARKET_CONFIG_SEED, market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_CONFIG_SEED, market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         space = 8 + MarketConfig::INIT_SPACE,
         bump,
     )]
@@ -36,7 +40,7 @@ pub struct ProposeMarket<'info> {
     #[account(
         init,
         payer = creator,
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         space = 8 + MarketState::INIT_SPACE,
         bump,
     )]
@@ -45,33 +49,77 @@ pub struct ProposeMarket<'info> {
     #[account(seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump)]
     pub market_vault: SystemAccount<'info>,
 
+    /// CHECK: This is the tombstone of a dismissed market with the same ID, which must not exist
+    #[account(seeds = [MARKET_TOMBSTONE_SEED, market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()], bump)]
+    pub market_tombstone: UncheckedAccount<'info>,
+
+    /// Mint the market is denominated in, or `None` for a SOL market
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = stake_mint,
+        associated_token::authority = market_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ProposeMarket<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn propose_market(
         &mut self,
         market_id: u64,
         start_time: i64,
         end_time: i64,
+        resolution_deadline: i64,
         min_prediction_price: u64,
+        sigma_min: u64,
+        sigma_max: u64,
+        stake_weighting: StakeWeighting,
+        scoring_curve: ScoringCurve,
+        settlement_mode: SettlementMode,
+        oracle: Option<PriceOracle>,
+        creator_bond: u64,
         question: String,
         description: String,
         bumps: &ProposeMarketBumps,
-    ) -> Result<()> {
+    ) -> Result<MarketProposed> {
+        require!(
+            !self.platform_config.is_paused,
+            PlatformError::PlatformPaused
+        );
+
         let now = Clock::get()?.unix_timestamp;
 
+        require!(
+            self.market_tombstone.data_is_empty(),
+            MarketError::MarketIdDismissed
+        );
+
         require!(start_time >= now, MarketError::StartTimeInPast);
 
         require!(end_time >= start_time, MarketError::InvalidEndTime);
 
         require!(
-            question.as_bytes().len() <= MARKET_QUESTION_MAX_LEN,
+            resolution_deadline > end_time,
+            MarketError::InvalidResolutionDeadline
+        );
+
+        require!(
+            question.len() <= MARKET_QUESTION_MAX_LEN,
             MarketError::QuestionTooLong,
         );
 
         require!(
-            description.as_bytes().len() <= MARKET_DESCRIPTION_MAX_LEN,
+            description.len() <= MARKET_DESCRIPTION_MAX_LEN,
             MarketError::DescriptionTooLong
         );
 
@@ -80,14 +128,59 @@ impl<'info> ProposeMarket<'info> {
             MarketError::MinPredictionPriceZero
         );
 
-        // 1. Collect the market proposal fee
-        let cpi_program = self.system_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.creator.to_account_info(),
-            to: self.platform_treasury.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        transfer(cpi_ctx, self.platform_config.market_proposal_fee)?;
+        require!(
+            sigma_min > 0 && sigma_min <= sigma_max,
+            MarketError::InvalidSigmaRange
+        );
+
+        if let Some(oracle) = oracle {
+            require!(
+                oracle.max_staleness > 0 && oracle.exponent.unsigned_abs() <= 18,
+                MarketError::InvalidOracleConfig
+            );
+
+            if let Some(sampling) = oracle.sampling {
+                require!(
+                    sampling.window > 0
+                        && sampling.min_interval > 0
+                        && sampling.min_samples > 0
+                        && sampling.min_samples as usize <= MAX_PRICE_SAMPLES,
+                    OracleError::InvalidSampling
+                );
+            }
+        }
+
+        require!(
+            creator_bond == 0 || oracle.is_none(),
+            MarketError::InvalidCreatorBond
+        );
+
+        if self.stake_mint.is_some() {
+            required(&self.vault_token_account)?;
+        }
+
+        // 1. Escrow the market proposal fee in the market vault
+        let proposal_fee = self.platform_config.market_proposal_fee;
+        if proposal_fee > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: self.creator.to_account_info(),
+                to: self.market_vault.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            transfer(cpi_ctx, proposal_fee)?;
+        }
+
+        // Lock the creator bond in the market vault
+        if creator_bond > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: self.creator.to_account_info(),
+                to: self.market_vault.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            transfer(cpi_ctx, creator_bond)?;
+        }
 
         // 2. Initialize the market accounts
         self.market_config.set_inner(MarketConfig {
@@ -96,7 +189,19 @@ impl<'info> ProposeMarket<'info> {
             market_id,
             start_time,
             end_time,
+            resolution_deadline,
             min_prediction_price,
+            sigma_min,
+            sigma_max,
+            stake_weighting,
+            scoring_curve,
+            settlement_mode,
+            stake_mint: self.stake_mint.as_ref().map(|mint| mint.key()),
+            oracle,
+            resolver: None,
+            creator_bond,
+            proposal_fee,
+            revision: 0,
             question,
             description,
             creator: self.creator.key(),
@@ -105,17 +210,46 @@ impl<'info> ProposeMarket<'info> {
 
         self.market_state.set_inner(MarketState {
             bump: bumps.market_state,
-            decay: ((end_time - start_time) as u64) * FIXED_POINT_SCALE / DECAY_DURATION_SCALE,
-            is_approved: false,
-            is_resolved: false,
+            status: MarketStatus::Proposed,
+            is_expired: false,
+            has_claims: false,
             resolution: None,
+            resolution_amendments: 0,
+            refund_policy: None,
+            dispute_ends_at: 0,
+            open_disputes: 0,
+            attestations: Vec::new(),
+            creator_bond_locked: creator_bond > 0,
+            proposed_resolution: None,
+            proposal_ends_at: 0,
+            challenger: None,
+            challenged_resolution: None,
+            challenger_bond: 0,
             total_pool: 0,
             total_positions: 0,
-            total_scores: None,
+            scored_positions: 0,
+            total_scores: 0,
+            settlement_root: None,
+            settlement_payout: 0,
+            claimed_payout: 0,
             creator_fee_revenue: 0,
+            platform_fee_revenue: 0,
             market_config: self.market_config.key(),
         });
 
-        Ok(())
+        Ok(MarketProposed {
+            market: self.market_config.key(),
+            market_id,
+            creator: self.creator.key(),
+            proposal_fee,
+            creator_bond,
+            stake_mint: self.market_config.stake_mint,
+            start_time,
+            end_time,
+            resolution_deadline,
+            min_prediction_price,
+            sigma_min,
+            sigma_max,
+        })
     }
 }
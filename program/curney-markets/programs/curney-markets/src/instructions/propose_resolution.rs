@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::{BondError, MarketError};
+use crate::events::ResolutionProposed;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeResolution<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeResolution<'info> {
+    /// Proposes the outcome of a bonded market. It becomes final unless challenged within
+    /// the liveness period, which is the platform's dispute period.
+    pub fn propose_resolution(&mut self, resolution: i64) -> Result<ResolutionProposed> {
+        require!(
+            self.creator.key() == self.market_config.creator,
+            MarketError::Unauthorized
+        );
+
+        require!(
+            self.market_config.creator_bond > 0,
+            MarketError::InvalidResolutionMode
+        );
+
+        require!(
+            self.market_state.proposed_resolution.is_none(),
+            BondError::ResolutionAlreadyProposed
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Closed])?;
+
+        require!(
+            now < self.market_config.resolution_deadline,
+            MarketError::ResolutionDeadlinePassed
+        );
+
+        self.market_state.proposal_ends_at = now
+            .checked_add(self.platform_config.dispute_period)
+            .ok_or(MarketError::MathOverflow)?;
+        self.market_state.proposed_resolution = Some(resolution);
+
+        Ok(ResolutionProposed {
+            market: self.market_config.key(),
+            creator: self.creator.key(),
+            resolution,
+            creator_bond: self.market_config.creator_bond,
+            proposal_ends_at: self.market_state.proposal_ends_at,
+        })
+    }
+}
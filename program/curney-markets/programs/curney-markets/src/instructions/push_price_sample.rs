@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MAX_PRICE_SAMPLES, PLATFORM_CONFIG_SEED,
+    PRICE_SAMPLES_SEED,
+};
+use crate::error::{MarketError, OracleError};
+use crate::events::PriceSampleRecorded;
+use crate::oracle::{scale_price, PriceUpdate};
+use crate::state::{
+    MarketConfig, MarketState, MarketStatus, PlatformConfig, PriceSample, PriceSamples,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PushPriceSample<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(
+        mut,
+        seeds = [PRICE_SAMPLES_SEED, market_config.key().as_ref()],
+        bump = price_samples.bump,
+    )]
+    pub price_samples: Account<'info, PriceSamples>,
+
+    /// CHECK: This is a Pyth price update, its owner, feed ID and verification level are validated in the instruction handler
+    pub price_update: UncheckedAccount<'info>,
+}
+
+impl<'info> PushPriceSample<'info> {
+    /// Records the price in a caller-supplied update for the market's feed as a sample, provided it was published inside the
+    /// sampling window and at least the minimum interval after the previous sample. Anyone
+    /// can push samples; once the buffer is full the oldest sample is overwritten.
+    pub fn push_price_sample(&mut self) -> Result<PriceSampleRecorded> {
+        let oracle = self
+            .market_config
+            .oracle
+            .ok_or(MarketError::InvalidResolutionMode)?;
+
+        let sampling = oracle.sampling.ok_or(OracleError::SamplingNotEnabled)?;
+
+        // Prices published before the end time may only land on chain after it
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[
+                MarketStatus::Approved,
+                MarketStatus::Trading,
+                MarketStatus::Closed,
+            ],
+        )?;
+
+        let price_update = PriceUpdate::try_from_account(&self.price_update)?;
+
+        require!(price_update.fully_verified, OracleError::UnverifiedPrice);
+
+        require!(
+            price_update.feed_id == oracle.feed_id,
+            OracleError::FeedIdMismatch
+        );
+
+        let window_start = self
+            .market_config
+            .end_time
+            .checked_sub(sampling.window)
+            .ok_or(MarketError::MathOverflow)?;
+
+        require!(
+            price_update.publish_time >= window_start
+                && price_update.publish_time <= self.market_config.end_time,
+            OracleError::SampleOutsideWindow
+        );
+
+        require!(
+            price_update.publish_time
+                >= self
+                    .price_samples
+                    .last_publish_time
+                    .saturating_add(sampling.min_interval),
+            OracleError::SampleTooSoon
+        );
+
+        let sample = PriceSample {
+            publish_time: price_update.publish_time,
+            price: scale_price(price_update.price, price_update.exponent, oracle.exponent)?,
+        };
+
+        let samples = &mut self.price_samples;
+        if samples.samples.len() < MAX_PRICE_SAMPLES {
+            samples.samples.push(sample);
+        } else {
+            let index = samples.next_index as usize;
+            samples.samples[index] = sample;
+        }
+        samples.next_index = ((samples.next_index as usize + 1) % MAX_PRICE_SAMPLES) as u16;
+        samples.last_publish_time = sample.publish_time;
+
+        Ok(PriceSampleRecorded {
+            market: self.market_config.key(),
+            publish_time: sample.publish_time,
+            price: sample.price,
+            samples: samples.samples.len() as u16,
+        })
+    }
+}
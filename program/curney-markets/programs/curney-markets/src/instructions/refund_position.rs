@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::bond::pay_from_vault;
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED, POSITION_SEED,
+};
+use crate::error::{MarketError, PositionError};
+use crate::events::StakeRefunded;
+use crate::state::{
+    FeeRefundPolicy, MarketConfig, MarketState, MarketStatus, PlatformConfig, Position,
+};
+use crate::token::{required, transfer_tokens, vault_token_accounts};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, position.index.to_le_bytes().as_ref(), user.key().as_ref(), market_config.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefundPosition<'info> {
+    pub fn refund_position(&mut self) -> Result<StakeRefunded> {
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[MarketStatus::Voided],
+        )?;
+
+        require!(!self.position.claimed, PositionError::StakeAlreadyRefunded);
+
+        let refund_policy = self
+            .market_state
+            .refund_policy
+            .ok_or(MarketError::MarketNotVoided)?;
+
+        // The vault holds the net stake along with both escrowed fees
+        let refund = match refund_policy {
+            FeeRefundPolicy::StakeOnly => self.position.stake,
+            FeeRefundPolicy::StakeAndCreatorFee => self
+                .position
+                .stake
+                .checked_add(self.position.creator_fee)
+                .ok_or(MarketError::MathOverflow)?,
+            FeeRefundPolicy::GrossStake => self
+                .position
+                .stake
+                .checked_add(self.position.creator_fee)
+                .and_then(|amount| amount.checked_add(self.position.platform_fee))
+                .ok_or(MarketError::MathOverflow)?,
+        };
+
+        match self.market_config.stake_mint {
+            None => pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                self.user.to_account_info(),
+                &self.system_program,
+                refund,
+            )?,
+            Some(_) => self.refund_tokens(refund)?,
+        }
+
+        self.position.claimed = true;
+
+        Ok(StakeRefunded {
+            market: self.market_config.key(),
+            position: self.position.key(),
+            user: self.user.key(),
+            amount: refund,
+        })
+    }
+
+    fn refund_tokens(&self, refund: u64) -> Result<()> {
+        let vault = vault_token_accounts(
+            self.market_config.stake_mint,
+            &self.market_vault.key(),
+            &self.stake_mint,
+            &self.vault_token_account,
+            &self.token_program,
+        )?;
+        let user_token_account = required(&self.user_token_account)?;
+
+        let seeds = &[
+            MARKET_VAULT_SEED,
+            self.market_config.to_account_info().key.as_ref(),
+            &[self.market_config.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer_tokens(
+            vault.vault_token_account,
+            user_token_account,
+            self.market_vault.to_account_info(),
+            vault.stake_mint,
+            vault.token_program,
+            refund,
+            signer_seeds,
+        )
+    }
+}
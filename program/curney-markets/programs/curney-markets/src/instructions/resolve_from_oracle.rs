@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED, PRICE_SAMPLES_SEED,
+};
+use crate::error::{MarketError, OracleError};
+use crate::events::MarketResolved;
+use crate::oracle::{scale_price, PriceUpdate};
+use crate::sampling::aggregate_samples;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig, PriceSamples};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveFromOracle<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    /// CHECK: This is a Pyth price update, its owner, feed ID and verification level are validated in the instruction handler
+    pub price_update: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PRICE_SAMPLES_SEED, market_config.key().as_ref()],
+        bump = price_samples.bump,
+    )]
+    pub price_samples: Option<Account<'info, PriceSamples>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResolveFromOracle<'info> {
+    /// Resolves an oracle market to a fully verified price for the market's feed, taken from
+    /// any `PriceUpdateV2` account the caller supplies. The price must be published between
+    /// the end time and the end of the staleness window, which bounds how far from the end
+    /// time a caller can reach for a convenient price. Markets with price sampling resolve to
+    /// the aggregate of their pushed samples instead. Anyone can call this.
+    pub fn resolve_from_oracle(&mut self) -> Result<MarketResolved> {
+        let oracle = self
+            .market_config
+            .oracle
+            .ok_or(MarketError::InvalidResolutionMode)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Closed])?;
+
+        require!(
+            now < self.market_config.resolution_deadline,
+            MarketError::ResolutionDeadlinePassed
+        );
+
+        let resolution = match oracle.sampling {
+            Some(sampling) => {
+                let price_samples = self
+                    .price_samples
+                    .as_ref()
+                    .ok_or(OracleError::NoPriceSamples)?;
+
+                aggregate_samples(
+                    &price_samples.samples,
+                    sampling,
+                    self.market_config.end_time,
+                )?
+            }
+            None => {
+                let price_update = PriceUpdate::try_from_account(&self.price_update)?;
+
+                require!(price_update.fully_verified, OracleError::UnverifiedPrice);
+
+                require!(
+                    price_update.feed_id == oracle.feed_id,
+                    OracleError::FeedIdMismatch
+                );
+
+                let window_end = self
+                    .market_config
+                    .end_time
+                    .checked_add(oracle.max_staleness)
+                    .ok_or(MarketError::MathOverflow)?;
+
+                require!(
+                    price_update.publish_time >= self.market_config.end_time
+                        && price_update.publish_time <= window_end,
+                    OracleError::PriceOutsideWindow
+                );
+
+                scale_price(price_update.price, price_update.exponent, oracle.exponent)?
+            }
+        };
+
+        self.market_state.dispute_ends_at = now
+            .checked_add(self.platform_config.dispute_period)
+            .ok_or(MarketError::MathOverflow)?;
+        self.market_state.resolution = Some(resolution);
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Resolved)?;
+
+        Ok(MarketResolved {
+            market: self.market_config.key(),
+            resolution,
+            dispute_ends_at: self.market_state.dispute_ends_at,
+        })
+    }
+}
@@ -1,29 +1,45 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
-use crate::error::MarketError;
-use crate::state::{MarketConfig, MarketState, PlatformConfig};
+use crate::committee::{flag_outliers, median, record_flags};
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED, RESOLVER_COMMITTEE_SEED,
+};
+use crate::error::{CommitteeError, MarketError};
+use crate::events::{AttestationSubmitted, MarketResolved};
+use crate::state::{
+    Attestation, MarketConfig, MarketState, MarketStatus, PlatformConfig, ResolverCommittee,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub resolver: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
+    /// Only needed for committee attestations, so platforms without a committee can still
+    /// resolve through the designated and platform resolvers
     #[account(
-        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        mut,
+        seeds = [RESOLVER_COMMITTEE_SEED, platform_config.namespace.as_ref()],
+        bump = resolver_committee.bump,
+    )]
+    pub resolver_committee: Option<Account<'info, ResolverCommittee>>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         bump = market_config.bump,
     )]
     pub market_config: Account<'info, MarketConfig>,
 
     #[account(
         mut,
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         bump = market_state.bump,
     )]
     pub market_state: Account<'info, MarketState>,
@@ -32,32 +48,122 @@ pub struct ResolveMarket<'info> {
 }
 
 impl<'info> ResolveMarket<'info> {
-    pub fn resolve_market(&mut self, resolution: i64, total_scores: u128) -> Result<()> {
+    /// Resolves the market directly when signed by its designated resolver, or by the platform
+    /// resolver. Otherwise records a committee member's attestation of the outcome, and the
+    /// attestation that brings the count to the committee threshold resolves the market to the
+    /// median. Markets with a designated resolver only accept the platform resolver and
+    /// attestations once the resolver grace period is over. Only attestations from current
+    /// committee members count towards the threshold.
+    /// Returns the attestation event for committee attestations, and the resolution event
+    /// once the market is resolved.
+    pub fn resolve_market(
+        &mut self,
+        resolution: i64,
+    ) -> Result<(Option<AttestationSubmitted>, Option<MarketResolved>)> {
         require!(
-            self.admin.key() == self.platform_config.admin,
-            MarketError::Unauthorized
+            self.market_config.oracle.is_none() && self.market_config.creator_bond == 0,
+            MarketError::InvalidResolutionMode
         );
 
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Closed])?;
+
         require!(
-            self.market_state.is_approved,
-            MarketError::MarketNotApproved
+            now < self.market_config.resolution_deadline,
+            MarketError::ResolutionDeadlinePassed
         );
 
+        if self.market_config.resolver == Some(self.resolver.key()) {
+            return Ok((None, Some(self.finalize(resolution, now)?)));
+        }
+
+        let is_platform_resolver = self.resolver.key() == self.platform_config.resolver;
+        let is_member = self
+            .resolver_committee
+            .as_ref()
+            .is_some_and(|committee| committee.members.contains(&self.resolver.key()));
+
         require!(
-            !self.market_state.is_resolved,
-            MarketError::MarketAlreadyResolved
+            is_platform_resolver || is_member,
+            CommitteeError::NotCommitteeMember
         );
 
-        let now = Clock::get()?.unix_timestamp;
+        if self.market_config.resolver.is_some() {
+            let grace_period_end = self
+                .market_config
+                .end_time
+                .checked_add(self.platform_config.resolver_grace_period)
+                .ok_or(MarketError::MathOverflow)?;
+            require!(now >= grace_period_end, MarketError::ResolverGracePeriod);
+        }
+
+        if is_platform_resolver {
+            return Ok((None, Some(self.finalize(resolution, now)?)));
+        }
+
+        let committee = self
+            .resolver_committee
+            .as_mut()
+            .ok_or(CommitteeError::NotCommitteeMember)?;
+
+        // Attestations from members removed since attesting no longer count, and free their slot
+        let members = &committee.members;
+        self.market_state
+            .attestations
+            .retain(|a| members.contains(&a.member));
+
         require!(
-            now >= self.market_config.end_time,
-            MarketError::MarketNotEnded
+            !self
+                .market_state
+                .attestations
+                .iter()
+                .any(|a| a.member == self.resolver.key()),
+            CommitteeError::AlreadyAttested
+        );
+
+        self.market_state.attestations.push(Attestation {
+            member: self.resolver.key(),
+            value: resolution,
+            is_flagged: false,
+        });
+
+        let attestation = AttestationSubmitted {
+            market: self.market_config.key(),
+            member: self.resolver.key(),
+            value: resolution,
+            attestations: self.market_state.attestations.len() as u8,
+            threshold: committee.threshold,
+        };
+
+        if self.market_state.attestations.len() < committee.threshold as usize {
+            return Ok((Some(attestation), None));
+        }
+
+        let resolution =
+            median(&self.market_state.attestations).ok_or(MarketError::MarketNotResolved)?;
+        flag_outliers(
+            &mut self.market_state.attestations,
+            resolution,
+            committee.tolerance,
         );
+        record_flags(committee, &self.market_state.attestations);
+
+        Ok((Some(attestation), Some(self.finalize(resolution, now)?)))
+    }
 
-        self.market_state.total_scores = Some(total_scores);
+    fn finalize(&mut self, resolution: i64, now: i64) -> Result<MarketResolved> {
+        self.market_state.dispute_ends_at = now
+            .checked_add(self.platform_config.dispute_period)
+            .ok_or(MarketError::MathOverflow)?;
         self.market_state.resolution = Some(resolution);
-        self.market_state.is_resolved = true;
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Resolved)?;
 
-        Ok(())
+        Ok(MarketResolved {
+            market: self.market_config.key(),
+            resolution,
+            dispute_ends_at: self.market_state.dispute_ends_at,
+        })
     }
 }
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::bond::pay_from_vault;
+use crate::constants::{
+    MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED,
+};
+use crate::error::{BondError, MarketError};
+use crate::events::BondsReturned;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReturnBonds<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: This is the market creator and is validated in the instruction handler
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: This is the market challenger, if any, and is validated in the instruction handler
+    #[account(mut)]
+    pub challenger: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReturnBonds<'info> {
+    /// Returns the bonds still locked in a voided or expired market to their owners.
+    /// Anyone can call this.
+    pub fn return_bonds(&mut self) -> Result<BondsReturned> {
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[MarketStatus::Voided],
+        )?;
+
+        require!(
+            self.creator.key() == self.market_config.creator,
+            MarketError::InvalidCreator
+        );
+
+        require!(
+            self.market_state.creator_bond_locked || self.market_state.challenger_bond > 0,
+            BondError::NoBondsLocked
+        );
+
+        let mut event = BondsReturned {
+            market: self.market_config.key(),
+            creator: self.creator.key(),
+            creator_bond: 0,
+            challenger: None,
+            challenger_bond: 0,
+        };
+
+        if self.market_state.creator_bond_locked {
+            pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                self.creator.to_account_info(),
+                &self.system_program,
+                self.market_config.creator_bond,
+            )?;
+            self.market_state.creator_bond_locked = false;
+            event.creator_bond = self.market_config.creator_bond;
+        }
+
+        if self.market_state.challenger_bond > 0 {
+            let challenger = self
+                .challenger
+                .as_ref()
+                .ok_or(BondError::InvalidChallenger)?;
+
+            require!(
+                self.market_state.challenger == Some(challenger.key()),
+                BondError::InvalidChallenger
+            );
+
+            pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                challenger.to_account_info(),
+                &self.system_program,
+                self.market_state.challenger_bond,
+            )?;
+            event.challenger = Some(challenger.key());
+            event.challenger_bond = self.market_state.challenger_bond;
+            self.market_state.challenger_bond = 0;
+        }
+
+        Ok(event)
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISPUTE_SEED, MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::{DisputeError, MarketError};
+use crate::events::DisputeBondReturned;
+use crate::state::{Dispute, MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReturnDisputeBond<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: This is the dispute challenger and is validated in the instruction handler
+    #[account(mut)]
+    pub challenger: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [DISPUTE_SEED, market_config.key().as_ref(), dispute.challenger.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReturnDisputeBond<'info> {
+    /// Returns the bond and rent of a dispute left open when its market expired. Anyone can
+    /// call this.
+    pub fn return_dispute_bond(&mut self) -> Result<DisputeBondReturned> {
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[MarketStatus::Voided],
+        )?;
+
+        require!(
+            self.challenger.key() == self.dispute.challenger,
+            DisputeError::InvalidChallenger
+        );
+
+        require!(
+            self.dispute.market == self.market_config.key(),
+            DisputeError::InvalidMarket
+        );
+
+        self.market_state.open_disputes = self
+            .market_state
+            .open_disputes
+            .checked_sub(1)
+            .ok_or(MarketError::MathOverflow)?;
+
+        Ok(DisputeBondReturned {
+            market: self.market_config.key(),
+            dispute: self.dispute.key(),
+            challenger: self.challenger.key(),
+            bond: self.dispute.bond,
+        })
+    }
+}
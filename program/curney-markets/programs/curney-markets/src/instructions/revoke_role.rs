@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PLATFORM_CONFIG_SEED;
+use crate::error::PlatformError;
+use crate::events::RoleRevoked;
+use crate::state::{PlatformConfig, PlatformRole};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl<'info> RevokeRole<'info> {
+    /// Takes `role` from its holder and gives it back to the admin.
+    pub fn revoke_role(&mut self, role: PlatformRole) -> Result<RoleRevoked> {
+        require!(
+            self.admin.key() == self.platform_config.admin,
+            PlatformError::Unauthorized
+        );
+
+        let holder = self.platform_config.role_holder(role);
+        self.platform_config.set_role_holder(role, self.admin.key());
+
+        Ok(RoleRevoked {
+            platform_config: self.platform_config.key(),
+            role,
+            holder,
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PLATFORM_CONFIG_SEED;
+use crate::error::PlatformError;
+use crate::events::PlatformPauseSet;
+use crate::state::PlatformConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl<'info> SetPaused<'info> {
+    /// Stops or resumes new proposals, approvals and predictions. This is not a full emergency
+    /// stop: markets already live keep closing, and resolution, disputes, settlement, claims
+    /// and refunds keep working while paused, so funds are never locked by a pause.
+    pub fn set_paused(&mut self, is_paused: bool) -> Result<PlatformPauseSet> {
+        require!(
+            self.pauser.key() == self.platform_config.pauser,
+            PlatformError::Unauthorized
+        );
+
+        self.platform_config.is_paused = is_paused;
+
+        Ok(PlatformPauseSet {
+            platform_config: self.platform_config.key(),
+            pauser: self.pauser.key(),
+            is_paused,
+        })
+    }
+}
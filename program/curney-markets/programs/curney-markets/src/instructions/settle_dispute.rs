@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::{
+    DISPUTE_SEED, MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED,
+    PLATFORM_TREASURY_SEED,
+};
+use crate::error::{DisputeError, MarketError};
+use crate::events::DisputeSettled;
+use crate::state::{Dispute, MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleDispute<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// CHECK: This is the dispute challenger and is validated in the instruction handler
+    #[account(mut)]
+    pub challenger: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump = platform_config.treasury_bump)]
+    pub platform_treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [DISPUTE_SEED, market_config.key().as_ref(), dispute.challenger.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleDispute<'info> {
+    /// Overturning replaces the market resolution with the challenger's value, returns the
+    /// bond and pays a reward from the treasury, capped at what the treasury holds above its
+    /// rent reserve. Upholding slashes the bond to the treasury.
+    pub fn settle_dispute(&mut self, overturn: bool) -> Result<DisputeSettled> {
+        require!(
+            self.arbiter.key() == self.platform_config.arbiter,
+            MarketError::Unauthorized
+        );
+
+        require!(
+            self.challenger.key() == self.dispute.challenger,
+            DisputeError::InvalidChallenger
+        );
+
+        require!(
+            self.dispute.market == self.market_config.key(),
+            DisputeError::InvalidMarket
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Disputed])?;
+
+        let (reward, bond_slashed) = if overturn {
+            self.market_state.resolution = Some(self.dispute.proposed_resolution);
+
+            let reward = (self.platform_config.dispute_reward_bps as u64)
+                .checked_mul(self.dispute.bond)
+                .ok_or(MarketError::MathOverflow)?
+                / 10000;
+
+            // A drained treasury pays what it can above its rent reserve, so the ruling still goes through
+            let required_rent_reserve =
+                Rent::get()?.minimum_balance(self.platform_treasury.data_len());
+            let reward = reward.min(
+                self.platform_treasury
+                    .lamports()
+                    .saturating_sub(required_rent_reserve),
+            );
+
+            if reward > 0 {
+                let cpi_program = self.system_program.to_account_info();
+                let cpi_accounts = Transfer {
+                    from: self.platform_treasury.to_account_info(),
+                    to: self.challenger.to_account_info(),
+                };
+
+                let seeds = &[
+                    PLATFORM_TREASURY_SEED,
+                    self.platform_config.to_account_info().key.as_ref(),
+                    &[self.platform_config.treasury_bump],
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                transfer(cpi_ctx, reward)?;
+            }
+
+            (reward, 0)
+        } else {
+            // The bond goes to the treasury, the rent goes back to the challenger on close
+            self.dispute.sub_lamports(self.dispute.bond)?;
+            self.platform_treasury.add_lamports(self.dispute.bond)?;
+
+            (0, self.dispute.bond)
+        };
+
+        self.market_state.open_disputes = self
+            .market_state
+            .open_disputes
+            .checked_sub(1)
+            .ok_or(MarketError::MathOverflow)?;
+
+        if self.market_state.open_disputes == 0 {
+            self.market_state
+                .transition(&self.market_config, now, MarketStatus::Resolved)?;
+        }
+
+        Ok(DisputeSettled {
+            market: self.market_config.key(),
+            dispute: self.dispute.key(),
+            arbiter: self.arbiter.key(),
+            challenger: self.challenger.key(),
+            overturned: overturn,
+            resolution: self
+                .market_state
+                .resolution
+                .ok_or(MarketError::MarketNotResolved)?,
+            reward,
+            bond_slashed,
+            open_disputes: self.market_state.open_disputes,
+        })
+    }
+}
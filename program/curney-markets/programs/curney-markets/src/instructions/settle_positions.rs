@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::{MarketError, PositionError};
+use crate::events::PositionsSettled;
+use crate::scoring::{calculate_score, calculate_weighted_score};
+use crate::state::{
+    MarketConfig, MarketState, MarketStatus, PlatformConfig, Position, SettlementMode,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettlePositions<'info> {
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+}
+
+impl<'info> SettlePositions<'info> {
+    /// Scores a batch of positions, passed as writable remaining accounts, and adds
+    /// each stake-weighted score to the market total. Anyone can crank this once the market is resolved.
+    pub fn settle_positions(
+        &mut self,
+        positions: &'info [AccountInfo<'info>],
+    ) -> Result<PositionsSettled> {
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Resolved])?;
+
+        require!(
+            now >= self.market_state.dispute_ends_at,
+            MarketError::ResolutionNotFinal
+        );
+
+        require!(
+            self.market_config.settlement_mode == SettlementMode::OnChain,
+            MarketError::InvalidSettlementMode
+        );
+
+        require!(
+            self.market_state.scored_positions < self.market_state.total_positions,
+            MarketError::MarketAlreadySettled
+        );
+
+        let resolution = self
+            .market_state
+            .resolution
+            .ok_or(MarketError::MarketNotResolved)?;
+
+        for info in positions.iter() {
+            require!(info.is_writable, ErrorCode::AccountNotMutable);
+
+            let mut position = Account::<Position>::try_from(info)?;
+
+            require!(
+                position.market == self.market_config.key(),
+                PositionError::InvalidMarket
+            );
+
+            // Scores computed before the latest amendment are stale and get recomputed
+            require!(
+                position.score.is_none()
+                    || position.scored_amendment != self.market_state.resolution_amendments,
+                PositionError::PositionAlreadySettled
+            );
+
+            let score = calculate_weighted_score(
+                calculate_score(
+                    self.market_config.scoring_curve,
+                    position.prediction,
+                    resolution,
+                    position.sigma,
+                )?,
+                position.stake,
+                self.market_config.stake_weighting,
+            )?;
+            position.score = Some(score);
+            position.scored_amendment = self.market_state.resolution_amendments;
+
+            // Persist right away so a duplicate in the same batch sees the score
+            position.exit(&crate::ID)?;
+
+            self.market_state.total_scores = self
+                .market_state
+                .total_scores
+                .checked_add(score)
+                .ok_or(MarketError::MathOverflow)?;
+
+            self.market_state.scored_positions = self
+                .market_state
+                .scored_positions
+                .checked_add(1)
+                .ok_or(MarketError::MathOverflow)?;
+        }
+
+        let is_settled = self.market_state.scored_positions == self.market_state.total_positions;
+        if is_settled {
+            self.market_state
+                .transition(&self.market_config, now, MarketStatus::Settled)?;
+        }
+
+        Ok(PositionsSettled {
+            market: self.market_config.key(),
+            positions: positions.len() as u64,
+            scored_positions: self.market_state.scored_positions,
+            total_positions: self.market_state.total_positions,
+            total_scores: self.market_state.total_scores,
+            is_settled,
+        })
+    }
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+
+use crate::bond::pay_from_vault;
+use crate::constants::{
+    BASIS_POINT_SCALE, CHALLENGER_BOND_SHARE_BPS, MARKET_CONFIG_SEED, MARKET_STATE_SEED,
+    MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED,
+};
+use crate::error::{BondError, MarketError};
+use crate::events::{MarketResolved, ResolutionChallengeSettled};
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleResolutionChallenge<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// CHECK: This is the market creator and is validated in the instruction handler
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: This is the market challenger and is validated in the instruction handler
+    #[account(mut)]
+    pub challenger: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump = platform_config.treasury_bump)]
+    pub platform_treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleResolutionChallenge<'info> {
+    /// A successful challenge resolves the market to the challenger's outcome, returns their
+    /// bond and slashes the creator bond between the challenger and the treasury. A failed
+    /// one resolves the market to the proposal, returns the creator bond and slashes the
+    /// challenger bond to the treasury.
+    pub fn settle_resolution_challenge(
+        &mut self,
+        challenge_succeeds: bool,
+    ) -> Result<(MarketResolved, ResolutionChallengeSettled)> {
+        require!(
+            self.arbiter.key() == self.platform_config.arbiter,
+            MarketError::Unauthorized
+        );
+
+        require!(
+            self.creator.key() == self.market_config.creator,
+            MarketError::InvalidCreator
+        );
+
+        require!(
+            self.market_state.challenger == Some(self.challenger.key()),
+            BondError::InvalidChallenger
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Closed])?;
+
+        let creator_bond = self.market_config.creator_bond;
+        let challenger_bond = self.market_state.challenger_bond;
+
+        let (resolution, challenger_reward) = if challenge_succeeds {
+            let challenger_share = creator_bond
+                .checked_mul(CHALLENGER_BOND_SHARE_BPS as u64)
+                .ok_or(MarketError::MathOverflow)?
+                / BASIS_POINT_SCALE as u64;
+
+            pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                self.challenger.to_account_info(),
+                &self.system_program,
+                challenger_bond
+                    .checked_add(challenger_share)
+                    .ok_or(MarketError::MathOverflow)?,
+            )?;
+            pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                self.platform_treasury.to_account_info(),
+                &self.system_program,
+                creator_bond - challenger_share,
+            )?;
+
+            let resolution = self
+                .market_state
+                .challenged_resolution
+                .ok_or(BondError::NotChallenged)?;
+
+            (resolution, challenger_share)
+        } else {
+            pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                self.creator.to_account_info(),
+                &self.system_program,
+                creator_bond,
+            )?;
+            pay_from_vault(
+                &self.market_config,
+                &self.market_vault,
+                self.platform_treasury.to_account_info(),
+                &self.system_program,
+                challenger_bond,
+            )?;
+
+            let resolution = self
+                .market_state
+                .proposed_resolution
+                .ok_or(BondError::ResolutionNotProposed)?;
+
+            (resolution, 0)
+        };
+
+        self.market_state.creator_bond_locked = false;
+        self.market_state.challenger_bond = 0;
+
+        self.market_state.dispute_ends_at = now;
+        self.market_state.resolution = Some(resolution);
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Resolved)?;
+
+        Ok((
+            MarketResolved {
+                market: self.market_config.key(),
+                resolution,
+                dispute_ends_at: now,
+            },
+            ResolutionChallengeSettled {
+                market: self.market_config.key(),
+                arbiter: self.arbiter.key(),
+                challenger: self.challenger.key(),
+                challenge_succeeds,
+                creator_bond_slashed: if challenge_succeeds { creator_bond } else { 0 },
+                challenger_reward,
+                challenger_bond_slashed: if challenge_succeeds {
+                    0
+                } else {
+                    challenger_bond
+                },
+            },
+        ))
+    }
+}
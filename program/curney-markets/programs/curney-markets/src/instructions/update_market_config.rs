@@ -5,28 +5,34 @@ use crate::constants::{
     PLATFORM_CONFIG_SEED,
 };
 use crate::error::MarketError;
-use crate::state::{MarketConfig, MarketState, PlatformConfig};
+use crate::events::MarketUpdated;
+use crate::state::{
+    MarketConfig, MarketState, MarketStatus, PlatformConfig, ScoringCurve, SettlementMode,
+    StakeWeighting,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateMarketConfig<'info> {
+    /// The platform approver, or the market creator for the fields they may edit
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
     #[account(
         mut,
-        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         bump = market_config.bump,
     )]
     pub market_config: Account<'info, MarketConfig>,
 
     #[account(
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         bump = market_state.bump,
     )]
     pub market_state: Account<'info, MarketState>,
@@ -35,31 +41,47 @@ pub struct UpdateMarketConfig<'info> {
 }
 
 impl<'info> UpdateMarketConfig<'info> {
+    /// Edits a market awaiting approval and bumps its revision. The creator may only change
+    /// the question, description, times and min prediction price, the approver can change
+    /// everything.
+    #[allow(clippy::too_many_arguments)]
     pub fn update_market_config(
         &mut self,
         start_time: Option<i64>,
         end_time: Option<i64>,
+        resolution_deadline: Option<i64>,
         min_prediction_price: Option<u64>,
+        sigma_min: Option<u64>,
+        sigma_max: Option<u64>,
+        stake_weighting: Option<StakeWeighting>,
+        scoring_curve: Option<ScoringCurve>,
+        settlement_mode: Option<SettlementMode>,
         question: Option<String>,
         description: Option<String>,
-    ) -> Result<()> {
+    ) -> Result<MarketUpdated> {
+        let is_approver = self.authority.key() == self.platform_config.approver;
+
         require!(
-            self.admin.key() == self.platform_config.admin,
+            is_approver || self.authority.key() == self.market_config.creator,
             MarketError::Unauthorized
         );
 
         require!(
-            !self.market_state.is_approved,
-            MarketError::MarketAlreadyApproved
+            is_approver
+                || (sigma_min.is_none()
+                    && sigma_max.is_none()
+                    && stake_weighting.is_none()
+                    && scoring_curve.is_none()
+                    && settlement_mode.is_none()),
+            MarketError::CreatorEditNotAllowed
         );
 
-        require!(
-            !self.market_state.is_resolved,
-            MarketError::MarketAlreadyResolved
-        );
+        let now = Clock::get()?.unix_timestamp;
+
+        self.market_state
+            .require_status(&self.market_config, now, &[MarketStatus::Proposed])?;
 
         if let Some(start) = start_time {
-            let now = Clock::get()?.unix_timestamp;
             require!(start > now, MarketError::StartTimeInPast);
             let end_check = end_time.unwrap_or(self.market_config.end_time);
             require!(start < end_check, MarketError::InvalidEndTime);
@@ -70,20 +92,39 @@ impl<'info> UpdateMarketConfig<'info> {
             require!(end > start_check, MarketError::InvalidEndTime);
         }
 
+        if end_time.is_some() || resolution_deadline.is_some() {
+            let end_check = end_time.unwrap_or(self.market_config.end_time);
+            let deadline_check =
+                resolution_deadline.unwrap_or(self.market_config.resolution_deadline);
+            require!(
+                deadline_check > end_check,
+                MarketError::InvalidResolutionDeadline
+            );
+        }
+
         if let Some(min) = min_prediction_price {
             require!(min > 0, MarketError::MinPredictionPriceZero);
         }
 
+        if sigma_min.is_some() || sigma_max.is_some() {
+            let min_check = sigma_min.unwrap_or(self.market_config.sigma_min);
+            let max_check = sigma_max.unwrap_or(self.market_config.sigma_max);
+            require!(
+                min_check > 0 && min_check <= max_check,
+                MarketError::InvalidSigmaRange
+            );
+        }
+
         if let Some(ref q) = question {
             require!(
-                q.as_bytes().len() <= MARKET_QUESTION_MAX_LEN,
+                q.len() <= MARKET_QUESTION_MAX_LEN,
                 MarketError::QuestionTooLong
             );
         }
 
         if let Some(ref d) = description {
             require!(
-                d.as_bytes().len() <= MARKET_DESCRIPTION_MAX_LEN,
+                d.len() <= MARKET_DESCRIPTION_MAX_LEN,
                 MarketError::DescriptionTooLong
             );
         }
@@ -96,10 +137,34 @@ impl<'info> UpdateMarketConfig<'info> {
             self.market_config.end_time = v;
         }
 
+        if let Some(v) = resolution_deadline {
+            self.market_config.resolution_deadline = v;
+        }
+
         if let Some(v) = min_prediction_price {
             self.market_config.min_prediction_price = v;
         }
 
+        if let Some(v) = sigma_min {
+            self.market_config.sigma_min = v;
+        }
+
+        if let Some(v) = sigma_max {
+            self.market_config.sigma_max = v;
+        }
+
+        if let Some(v) = stake_weighting {
+            self.market_config.stake_weighting = v;
+        }
+
+        if let Some(v) = scoring_curve {
+            self.market_config.scoring_curve = v;
+        }
+
+        if let Some(v) = settlement_mode {
+            self.market_config.settlement_mode = v;
+        }
+
         if let Some(v) = question {
             self.market_config.question = v;
         }
@@ -108,6 +173,22 @@ impl<'info> UpdateMarketConfig<'info> {
             self.market_config.description = v;
         }
 
-        Ok(())
+        self.market_config.revision = self
+            .market_config
+            .revision
+            .checked_add(1)
+            .ok_or(MarketError::MathOverflow)?;
+
+        Ok(MarketUpdated {
+            market: self.market_config.key(),
+            updated_by: self.authority.key(),
+            revision: self.market_config.revision,
+            start_time: self.market_config.start_time,
+            end_time: self.market_config.end_time,
+            resolution_deadline: self.market_config.resolution_deadline,
+            min_prediction_price: self.market_config.min_prediction_price,
+            sigma_min: self.market_config.sigma_min,
+            sigma_max: self.market_config.sigma_max,
+        })
     }
 }
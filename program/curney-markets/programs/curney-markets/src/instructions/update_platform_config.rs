@@ -2,16 +2,19 @@ use anchor_lang::prelude::*;
 
 use crate::constants::{BASIS_POINT_SCALE, PLATFORM_CONFIG_SEED};
 use crate::error::PlatformError;
+use crate::events::PlatformConfigUpdated;
 use crate::state::PlatformConfig;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdatePlatformConfig<'info> {
+    /// The treasurer for the fee settings, the admin for the rest
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
@@ -20,12 +23,52 @@ pub struct UpdatePlatformConfig<'info> {
 }
 
 impl<'info> UpdatePlatformConfig<'info> {
+    /// Updates the platform settings. The fees and proposal refund rates belong to the
+    /// treasurer, the dispute and resolution settings to the admin.
+    #[allow(clippy::too_many_arguments)]
     pub fn update_platform_config(
         &mut self,
         creator_fee_bps: Option<u16>,
         platform_fee_bps: Option<u16>,
         market_proposal_fee: Option<u64>,
-    ) -> Result<()> {
+        dismissal_refund_bps: Option<u16>,
+        spam_dismissal_refund_bps: Option<u16>,
+        withdrawal_refund_bps: Option<u16>,
+        dispute_period: Option<i64>,
+        dispute_bond: Option<u64>,
+        dispute_reward_bps: Option<u16>,
+        resolver_grace_period: Option<i64>,
+        settlement_grace_period: Option<i64>,
+    ) -> Result<PlatformConfigUpdated> {
+        require!(
+            self.authority.key() == self.platform_config.treasurer
+                || self.authority.key() == self.platform_config.admin,
+            PlatformError::Unauthorized
+        );
+
+        let updates_fees = creator_fee_bps.is_some()
+            || platform_fee_bps.is_some()
+            || market_proposal_fee.is_some()
+            || dismissal_refund_bps.is_some()
+            || spam_dismissal_refund_bps.is_some()
+            || withdrawal_refund_bps.is_some();
+
+        let updates_settings = dispute_period.is_some()
+            || dispute_bond.is_some()
+            || dispute_reward_bps.is_some()
+            || resolver_grace_period.is_some()
+            || settlement_grace_period.is_some();
+
+        require!(
+            !updates_fees || self.authority.key() == self.platform_config.treasurer,
+            PlatformError::Unauthorized
+        );
+
+        require!(
+            !updates_settings || self.authority.key() == self.platform_config.admin,
+            PlatformError::Unauthorized
+        );
+
         if let Some(c) = creator_fee_bps {
             require!(c <= BASIS_POINT_SCALE, PlatformError::InvalidCreatorFeeBps);
         }
@@ -38,6 +81,50 @@ impl<'info> UpdatePlatformConfig<'info> {
             require!(fee > 0, PlatformError::InvalidMarketProposalFee);
         }
 
+        if let Some(r) = dismissal_refund_bps {
+            require!(
+                r <= BASIS_POINT_SCALE,
+                PlatformError::InvalidDismissalRefundBps
+            );
+        }
+
+        if let Some(r) = spam_dismissal_refund_bps {
+            require!(
+                r <= BASIS_POINT_SCALE,
+                PlatformError::InvalidDismissalRefundBps
+            );
+        }
+
+        if let Some(r) = withdrawal_refund_bps {
+            require!(
+                r <= BASIS_POINT_SCALE,
+                PlatformError::InvalidWithdrawalRefundBps
+            );
+        }
+
+        if let Some(period) = dispute_period {
+            require!(period >= 0, PlatformError::InvalidDisputePeriod);
+        }
+
+        if let Some(bond) = dispute_bond {
+            require!(bond > 0, PlatformError::InvalidDisputeBond);
+        }
+
+        if let Some(r) = dispute_reward_bps {
+            require!(
+                r <= BASIS_POINT_SCALE,
+                PlatformError::InvalidDisputeRewardBps
+            );
+        }
+
+        if let Some(period) = resolver_grace_period {
+            require!(period >= 0, PlatformError::InvalidResolverGracePeriod);
+        }
+
+        if let Some(period) = settlement_grace_period {
+            require!(period >= 0, PlatformError::InvalidSettlementGracePeriod);
+        }
+
         let new_creator_fee = creator_fee_bps.unwrap_or(self.platform_config.creator_fee_bps);
         let new_platform_fee = platform_fee_bps.unwrap_or(self.platform_config.platform_fee_bps);
         require!(
@@ -57,6 +144,51 @@ impl<'info> UpdatePlatformConfig<'info> {
             self.platform_config.market_proposal_fee = fee;
         }
 
-        Ok(())
+        if let Some(r) = dismissal_refund_bps {
+            self.platform_config.dismissal_refund_bps = r;
+        }
+
+        if let Some(r) = spam_dismissal_refund_bps {
+            self.platform_config.spam_dismissal_refund_bps = r;
+        }
+
+        if let Some(r) = withdrawal_refund_bps {
+            self.platform_config.withdrawal_refund_bps = r;
+        }
+
+        if let Some(period) = dispute_period {
+            self.platform_config.dispute_period = period;
+        }
+
+        if let Some(bond) = dispute_bond {
+            self.platform_config.dispute_bond = bond;
+        }
+
+        if let Some(r) = dispute_reward_bps {
+            self.platform_config.dispute_reward_bps = r;
+        }
+
+        if let Some(period) = resolver_grace_period {
+            self.platform_config.resolver_grace_period = period;
+        }
+
+        if let Some(period) = settlement_grace_period {
+            self.platform_config.settlement_grace_period = period;
+        }
+
+        Ok(PlatformConfigUpdated {
+            platform_config: self.platform_config.key(),
+            creator_fee_bps: self.platform_config.creator_fee_bps,
+            platform_fee_bps: self.platform_config.platform_fee_bps,
+            market_proposal_fee: self.platform_config.market_proposal_fee,
+            dismissal_refund_bps: self.platform_config.dismissal_refund_bps,
+            spam_dismissal_refund_bps: self.platform_config.spam_dismissal_refund_bps,
+            withdrawal_refund_bps: self.platform_config.withdrawal_refund_bps,
+            dispute_period: self.platform_config.dispute_period,
+            dispute_bond: self.platform_config.dispute_bond,
+            dispute_reward_bps: self.platform_config.dispute_reward_bps,
+            resolver_grace_period: self.platform_config.resolver_grace_period,
+            settlement_grace_period: self.platform_config.settlement_grace_period,
+        })
     }
 }
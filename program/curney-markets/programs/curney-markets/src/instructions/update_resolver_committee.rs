@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::committee::{carry_flag_counts, validate_committee};
+use crate::constants::{PLATFORM_CONFIG_SEED, RESOLVER_COMMITTEE_SEED};
+use crate::error::PlatformError;
+use crate::events::ResolverCommitteeUpdated;
+use crate::state::{PlatformConfig, ResolverCommittee};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateResolverCommittee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [RESOLVER_COMMITTEE_SEED, platform_config.namespace.as_ref()],
+        bump = resolver_committee.bump,
+    )]
+    pub resolver_committee: Account<'info, ResolverCommittee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateResolverCommittee<'info> {
+    pub fn update_resolver_committee(
+        &mut self,
+        members: Option<Vec<Pubkey>>,
+        threshold: Option<u8>,
+        tolerance: Option<u64>,
+    ) -> Result<ResolverCommitteeUpdated> {
+        require!(
+            self.admin.key() == self.platform_config.admin,
+            PlatformError::Unauthorized
+        );
+
+        let new_members = members.as_ref().unwrap_or(&self.resolver_committee.members);
+        let new_threshold = threshold.unwrap_or(self.resolver_committee.threshold);
+        validate_committee(new_members, new_threshold)?;
+
+        if let Some(m) = members {
+            self.resolver_committee.flag_counts = carry_flag_counts(&self.resolver_committee, &m);
+            self.resolver_committee.members = m;
+        }
+
+        if let Some(t) = threshold {
+            self.resolver_committee.threshold = t;
+        }
+
+        if let Some(t) = tolerance {
+            self.resolver_committee.tolerance = t;
+        }
+
+        Ok(ResolverCommitteeUpdated {
+            platform_config: self.platform_config.key(),
+            members: self.resolver_committee.members.clone(),
+            threshold: self.resolver_committee.threshold,
+            tolerance: self.resolver_committee.tolerance,
+        })
+    }
+}
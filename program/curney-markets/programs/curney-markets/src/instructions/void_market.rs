@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MARKET_CONFIG_SEED, MARKET_STATE_SEED, PLATFORM_CONFIG_SEED};
+use crate::error::MarketError;
+use crate::events::MarketVoided;
+use crate::state::{FeeRefundPolicy, MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoidMarket<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VoidMarket<'info> {
+    /// Voids the market with the given refund policy. A resolved or disputed market is always
+    /// voided with `StakeOnly`, since the creator and the platform may already hold their fees.
+    pub fn void_market(&mut self, refund_policy: FeeRefundPolicy) -> Result<MarketVoided> {
+        require!(
+            self.admin.key() == self.platform_config.admin,
+            MarketError::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let refund_policy = match self.market_state.status_at(&self.market_config, now) {
+            MarketStatus::Resolved | MarketStatus::Disputed => FeeRefundPolicy::StakeOnly,
+            _ => refund_policy,
+        };
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Voided)?;
+
+        // Creator fees owed back to users can no longer be withdrawn by the creator
+        if refund_policy != FeeRefundPolicy::StakeOnly {
+            self.market_state.creator_fee_revenue = 0;
+        }
+
+        // Likewise for the escrowed platform fees
+        if refund_policy == FeeRefundPolicy::GrossStake {
+            self.market_state.platform_fee_revenue = 0;
+        }
+
+        self.market_state.refund_policy = Some(refund_policy);
+
+        Ok(MarketVoided {
+            market: self.market_config.key(),
+            refund_policy,
+            is_expired: false,
+        })
+    }
+}
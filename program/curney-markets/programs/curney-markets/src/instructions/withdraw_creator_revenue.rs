@@ -1,32 +1,37 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+use crate::bond::locked_bonds;
 use crate::constants::{
     MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED, PLATFORM_CONFIG_SEED,
 };
 use crate::error::MarketError;
-use crate::state::{MarketConfig, MarketState, PlatformConfig};
+use crate::events::CreatorRevenueWithdrawn;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+use crate::token::{required, transfer_tokens, vault_token_accounts};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct WithdrawCreatorRevenue<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, platform_config.admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
     #[account(
-        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
         bump = market_config.bump,
     )]
     pub market_config: Account<'info, MarketConfig>,
 
     #[account(
         mut,
-        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.key().as_ref()],
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
         bump = market_state.bump,
     )]
     pub market_state: Account<'info, MarketState>,
@@ -34,29 +39,50 @@ pub struct WithdrawCreatorRevenue<'info> {
     #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
     pub market_vault: SystemAccount<'info>,
 
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub rent: Sysvar<'info, Rent>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> WithdrawCreatorRevenue<'info> {
-    pub fn withdraw_creator_revenue(&mut self) -> Result<()> {
+    pub fn withdraw_creator_revenue(&mut self) -> Result<CreatorRevenueWithdrawn> {
         require!(
             self.creator.key() == self.market_config.creator,
             MarketError::Unauthorized
         );
 
-        require!(
-            self.market_state.is_resolved,
-            MarketError::MarketNotResolved
-        );
-
-        let required_rent_reserve = self.rent.minimum_balance(self.market_vault.data_len());
-
-        let available_for_withdrawal = self
-            .market_vault
-            .lamports()
-            .saturating_sub(required_rent_reserve);
+        self.market_state.require_status(
+            &self.market_config,
+            Clock::get()?.unix_timestamp,
+            &[
+                MarketStatus::Resolved,
+                MarketStatus::Disputed,
+                MarketStatus::Settled,
+                MarketStatus::Voided,
+            ],
+        )?;
+
+        let available_for_withdrawal = match self.market_config.stake_mint {
+            None => {
+                let required_rent_reserve = self.rent.minimum_balance(self.market_vault.data_len());
+
+                self.market_vault
+                    .lamports()
+                    .saturating_sub(required_rent_reserve)
+                    .saturating_sub(locked_bonds(&self.market_config, &self.market_state))
+            }
+            Some(_) => required(&self.vault_token_account)?.amount,
+        };
 
         let withdrawal_amount = self
             .market_state
@@ -65,12 +91,6 @@ impl<'info> WithdrawCreatorRevenue<'info> {
 
         require!(withdrawal_amount > 0, MarketError::NothingToWithdraw);
 
-        let cpi_program = self.system_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.market_vault.to_account_info(),
-            to: self.creator.to_account_info(),
-        };
-
         let seeds = &[
             MARKET_VAULT_SEED,
             self.market_config.to_account_info().key.as_ref(),
@@ -78,14 +98,47 @@ impl<'info> WithdrawCreatorRevenue<'info> {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        transfer(cpi_ctx, withdrawal_amount)?;
+        match self.market_config.stake_mint {
+            None => {
+                let cpi_program = self.system_program.to_account_info();
+                let cpi_accounts = Transfer {
+                    from: self.market_vault.to_account_info(),
+                    to: self.creator.to_account_info(),
+                };
+
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                transfer(cpi_ctx, withdrawal_amount)?;
+            }
+            Some(_) => {
+                let vault = vault_token_accounts(
+                    self.market_config.stake_mint,
+                    &self.market_vault.key(),
+                    &self.stake_mint,
+                    &self.vault_token_account,
+                    &self.token_program,
+                )?;
+
+                transfer_tokens(
+                    vault.vault_token_account,
+                    required(&self.creator_token_account)?,
+                    self.market_vault.to_account_info(),
+                    vault.stake_mint,
+                    vault.token_program,
+                    withdrawal_amount,
+                    signer_seeds,
+                )?;
+            }
+        }
 
         self.market_state.creator_fee_revenue = self
             .market_state
             .creator_fee_revenue
             .saturating_sub(withdrawal_amount);
 
-        Ok(())
+        Ok(CreatorRevenueWithdrawn {
+            market: self.market_config.key(),
+            creator: self.creator.key(),
+            amount: withdrawal_amount,
+        })
     }
 }
@@ -1,17 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::{PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED};
 use crate::error::PlatformError;
+use crate::events::PlatformFeesWithdrawn;
 use crate::state::PlatformConfig;
+use crate::token::{require_associated_token_account, required, transfer_tokens};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct WithdrawPlatformFees<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub treasurer: Signer<'info>,
 
     #[account(
-        seeds = [PLATFORM_CONFIG_SEED, admin.key().as_ref()],
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
         bump = platform_config.bump,
     )]
     pub platform_config: Account<'info, PlatformConfig>,
@@ -23,18 +27,44 @@ pub struct WithdrawPlatformFees<'info> {
     )]
     pub platform_treasury: SystemAccount<'info>,
 
+    /// Mint whose treasury balance to withdraw, or `None` to withdraw SOL
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub treasurer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub rent: Sysvar<'info, Rent>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> WithdrawPlatformFees<'info> {
-    pub fn withdraw_platform_fees(&mut self) -> Result<()> {
+    pub fn withdraw_platform_fees(&mut self) -> Result<PlatformFeesWithdrawn> {
         require!(
-            self.admin.key() == self.platform_config.admin,
+            self.treasurer.key() == self.platform_config.treasurer,
             PlatformError::Unauthorized
         );
 
+        let amount = match &self.stake_mint {
+            None => self.withdraw_lamports()?,
+            Some(_) => self.withdraw_tokens()?,
+        };
+
+        Ok(PlatformFeesWithdrawn {
+            platform_config: self.platform_config.key(),
+            treasurer: self.treasurer.key(),
+            stake_mint: self.stake_mint.as_ref().map(|mint| mint.key()),
+            amount,
+        })
+    }
+
+    /// Withdraws the treasury's SOL above its rent reserve and returns the amount.
+    fn withdraw_lamports(&self) -> Result<u64> {
         let required_rent_reserve = self.rent.minimum_balance(self.platform_treasury.data_len());
 
         let withdrawal_amount = self
@@ -47,7 +77,7 @@ impl<'info> WithdrawPlatformFees<'info> {
         let cpi_program = self.system_program.to_account_info();
         let cpi_accounts = Transfer {
             from: self.platform_treasury.to_account_info(),
-            to: self.admin.to_account_info(),
+            to: self.treasurer.to_account_info(),
         };
 
         let seeds: &[&[u8]] = &[
@@ -60,6 +90,43 @@ impl<'info> WithdrawPlatformFees<'info> {
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
         transfer(cpi_ctx, withdrawal_amount)?;
 
-        Ok(())
+        Ok(withdrawal_amount)
+    }
+
+    /// Withdraws the treasury balance of the given stake mint and returns the amount.
+    fn withdraw_tokens(&self) -> Result<u64> {
+        let stake_mint = required(&self.stake_mint)?;
+        let token_program = required(&self.token_program)?;
+        let treasury_token_account = required(&self.treasury_token_account)?;
+
+        require_associated_token_account(
+            treasury_token_account,
+            &self.platform_treasury.key(),
+            stake_mint,
+            token_program,
+        )?;
+
+        let withdrawal_amount = treasury_token_account.amount;
+
+        require!(withdrawal_amount > 0, PlatformError::NothingToWithdraw);
+
+        let seeds: &[&[u8]] = &[
+            PLATFORM_TREASURY_SEED,
+            self.platform_config.to_account_info().key.as_ref(),
+            &[self.platform_config.treasury_bump],
+        ];
+        let signer_seeds = &[seeds];
+
+        transfer_tokens(
+            treasury_token_account,
+            required(&self.treasurer_token_account)?,
+            self.platform_treasury.to_account_info(),
+            stake_mint,
+            token_program,
+            withdrawal_amount,
+            signer_seeds,
+        )?;
+
+        Ok(withdrawal_amount)
     }
 }
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::bond::pay_from_vault;
+use crate::constants::{
+    BASIS_POINT_SCALE, MARKET_CONFIG_SEED, MARKET_STATE_SEED, MARKET_VAULT_SEED,
+    PLATFORM_CONFIG_SEED, PLATFORM_TREASURY_SEED,
+};
+use crate::error::MarketError;
+use crate::events::ProposalWithdrawn;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+use crate::token::{sweep_and_close_token_account, vault_token_accounts};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawProposal<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED, platform_config.platform_id.to_le_bytes().as_ref()],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut, seeds = [PLATFORM_TREASURY_SEED, platform_config.key().as_ref()], bump = platform_config.treasury_bump)]
+    pub platform_treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [MARKET_CONFIG_SEED, market_config.market_id.to_le_bytes().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_config.bump,
+    )]
+    pub market_config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [MARKET_STATE_SEED, market_config.key().as_ref(), platform_config.namespace.as_ref()],
+        bump = market_state.bump,
+    )]
+    pub market_state: Account<'info, MarketState>,
+
+    #[account(mut, seeds = [MARKET_VAULT_SEED, market_config.key().as_ref()], bump = market_config.vault_bump)]
+    pub market_vault: SystemAccount<'info>,
+
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives any tokens sent to the vault, only required when it holds a balance
+    #[account(mut)]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawProposal<'info> {
+    /// Lets the creator retract a market before it is approved. The market accounts are
+    /// closed and the escrowed proposal fee is refunded at the platform's withdrawal rate,
+    /// along with any creator bond. Unlike a dismissal, the market ID can be proposed again.
+    pub fn withdraw_proposal(&mut self) -> Result<ProposalWithdrawn> {
+        require!(
+            self.creator.key() == self.market_config.creator,
+            MarketError::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.market_state
+            .transition(&self.market_config, now, MarketStatus::Withdrawn)?;
+
+        // Keep the unrefunded share of the escrowed proposal fee
+        let proposal_fee = self.market_config.proposal_fee;
+        let proposal_fee_refund = proposal_fee
+            .checked_mul(self.platform_config.withdrawal_refund_bps as u64)
+            .ok_or(MarketError::MathOverflow)?
+            / BASIS_POINT_SCALE as u64;
+
+        pay_from_vault(
+            &self.market_config,
+            &self.market_vault,
+            self.platform_treasury.to_account_info(),
+            &self.system_program,
+            proposal_fee - proposal_fee_refund,
+        )?;
+
+        // Close the vault token account of a token market, sweeping any balance to the creator
+        if self.market_config.stake_mint.is_some() {
+            let vault = vault_token_accounts(
+                self.market_config.stake_mint,
+                &self.market_vault.key(),
+                &self.stake_mint,
+                &self.vault_token_account,
+                &self.token_program,
+            )?;
+
+            let seeds = &[
+                MARKET_VAULT_SEED,
+                self.market_config.to_account_info().key.as_ref(),
+                &[self.market_config.vault_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            sweep_and_close_token_account(
+                &vault,
+                &self.creator_token_account,
+                &self.creator.key(),
+                self.creator.to_account_info(),
+                self.market_vault.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+
+        // Return the fee refund and any creator bond, emptying the vault
+        pay_from_vault(
+            &self.market_config,
+            &self.market_vault,
+            self.creator.to_account_info(),
+            &self.system_program,
+            self.market_vault.lamports(),
+        )?;
+
+        Ok(ProposalWithdrawn {
+            market: self.market_config.key(),
+            market_id: self.market_config.market_id,
+            creator: self.creator.key(),
+            proposal_fee_refund,
+        })
+    }
+}
@@ -1,7 +1,17 @@
+pub mod bond;
+pub mod committee;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod lifecycle;
+pub mod merkle;
+pub mod oracle;
+pub mod roles;
+pub mod sampling;
+pub mod scoring;
 pub mod state;
+pub mod token;
 
 use anchor_lang::prelude::*;
 
@@ -15,77 +25,238 @@ declare_id!("HDowPKaGVPenpmncAMK5amt1i6XR8GteGSBNscbMLKcW");
 pub mod curney_markets {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_platform(
         ctx: Context<InitializePlatform>,
+        platform_id: u64,
         creator_fee_bps: u16,
         platform_fee_bps: u16,
         market_proposal_fee: u64,
+        dismissal_refund_bps: u16,
+        spam_dismissal_refund_bps: u16,
+        withdrawal_refund_bps: u16,
+        dispute_period: i64,
+        dispute_bond: u64,
+        dispute_reward_bps: u16,
+        resolver_grace_period: i64,
+        settlement_grace_period: i64,
     ) -> Result<()> {
-        ctx.accounts.initialize_platform(
+        let event = ctx.accounts.initialize_platform(
+            platform_id,
             creator_fee_bps,
             platform_fee_bps,
             market_proposal_fee,
+            dismissal_refund_bps,
+            spam_dismissal_refund_bps,
+            withdrawal_refund_bps,
+            dispute_period,
+            dispute_bond,
+            dispute_reward_bps,
+            resolver_grace_period,
+            settlement_grace_period,
             &ctx.bumps,
-        )
+        )?;
+        emit_cpi!(event);
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_platform_config(
         ctx: Context<UpdatePlatformConfig>,
         creator_fee_bps: Option<u16>,
         platform_fee_bps: Option<u16>,
         market_proposal_fee: Option<u64>,
+        dismissal_refund_bps: Option<u16>,
+        spam_dismissal_refund_bps: Option<u16>,
+        withdrawal_refund_bps: Option<u16>,
+        dispute_period: Option<i64>,
+        dispute_bond: Option<u64>,
+        dispute_reward_bps: Option<u16>,
+        resolver_grace_period: Option<i64>,
+        settlement_grace_period: Option<i64>,
     ) -> Result<()> {
-        ctx.accounts
-            .update_platform_config(creator_fee_bps, platform_fee_bps, market_proposal_fee)
+        let event = ctx.accounts.update_platform_config(
+            creator_fee_bps,
+            platform_fee_bps,
+            market_proposal_fee,
+            dismissal_refund_bps,
+            spam_dismissal_refund_bps,
+            withdrawal_refund_bps,
+            dispute_period,
+            dispute_bond,
+            dispute_reward_bps,
+            resolver_grace_period,
+            settlement_grace_period,
+        )?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn migrate_platform(ctx: Context<MigratePlatform>, platform_id: u64) -> Result<()> {
+        let event = ctx.accounts.migrate_platform(platform_id, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, pending_admin: Option<Pubkey>) -> Result<()> {
+        let event = ctx.accounts.propose_admin(pending_admin)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let event = ctx.accounts.accept_admin()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: PlatformRole, holder: Pubkey) -> Result<()> {
+        let event = ctx.accounts.grant_role(role, holder)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: PlatformRole) -> Result<()> {
+        let event = ctx.accounts.revoke_role(role)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, is_paused: bool) -> Result<()> {
+        let event = ctx.accounts.set_paused(is_paused)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn initialize_resolver_committee(
+        ctx: Context<InitializeResolverCommittee>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        tolerance: u64,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .initialize_resolver_committee(members, threshold, tolerance, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn update_resolver_committee(
+        ctx: Context<UpdateResolverCommittee>,
+        members: Option<Vec<Pubkey>>,
+        threshold: Option<u8>,
+        tolerance: Option<u64>,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .update_resolver_committee(members, threshold, tolerance)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn collect_platform_fees(ctx: Context<CollectPlatformFees>) -> Result<()> {
+        let event = ctx.accounts.collect_platform_fees()?;
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn withdraw_platform_fees(ctx: Context<WithdrawPlatformFees>) -> Result<()> {
-        ctx.accounts.withdraw_platform_fees()
+        let event = ctx.accounts.withdraw_platform_fees()?;
+        emit_cpi!(event);
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn propose_market(
         ctx: Context<ProposeMarket>,
         market_id: u64,
         start_time: i64,
         end_time: i64,
+        resolution_deadline: i64,
         min_prediction_price: u64,
+        sigma_min: u64,
+        sigma_max: u64,
+        stake_weighting: StakeWeighting,
+        scoring_curve: ScoringCurve,
+        settlement_mode: SettlementMode,
+        oracle: Option<PriceOracle>,
+        creator_bond: u64,
         question: String,
         description: String,
     ) -> Result<()> {
-        ctx.accounts.propose_market(
+        let event = ctx.accounts.propose_market(
             market_id,
             start_time,
             end_time,
+            resolution_deadline,
             min_prediction_price,
+            sigma_min,
+            sigma_max,
+            stake_weighting,
+            scoring_curve,
+            settlement_mode,
+            oracle,
+            creator_bond,
             question,
             description,
             &ctx.bumps,
-        )
+        )?;
+        emit_cpi!(event);
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_market_config(
         ctx: Context<UpdateMarketConfig>,
         start_time: Option<i64>,
         end_time: Option<i64>,
+        resolution_deadline: Option<i64>,
         min_prediction_price: Option<u64>,
+        sigma_min: Option<u64>,
+        sigma_max: Option<u64>,
+        stake_weighting: Option<StakeWeighting>,
+        scoring_curve: Option<ScoringCurve>,
+        settlement_mode: Option<SettlementMode>,
         question: Option<String>,
         description: Option<String>,
     ) -> Result<()> {
-        ctx.accounts.update_market_config(
+        let event = ctx.accounts.update_market_config(
             start_time,
             end_time,
+            resolution_deadline,
             min_prediction_price,
+            sigma_min,
+            sigma_max,
+            stake_weighting,
+            scoring_curve,
+            settlement_mode,
             question,
             description,
-        )
+        )?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn approve_market(
+        ctx: Context<ApproveMarket>,
+        resolver: Option<Pubkey>,
+        revision: u16,
+    ) -> Result<()> {
+        let event = ctx.accounts.approve_market(resolver, revision)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
-    pub fn approve_market(ctx: Context<ApproveMarket>) -> Result<()> {
-        ctx.accounts.approve_market()
+    pub fn dismiss_market(ctx: Context<DismissMarket>, reason: DismissReason) -> Result<()> {
+        let event = ctx.accounts.dismiss_market(reason, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
-    pub fn dismiss_market(ctx: Context<DismissMarket>) -> Result<()> {
-        ctx.accounts.dismiss_market()
+    pub fn withdraw_proposal(ctx: Context<WithdrawProposal>) -> Result<()> {
+        let event = ctx.accounts.withdraw_proposal()?;
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn place_prediction(
@@ -93,23 +264,162 @@ pub mod curney_markets {
         prediction: i64,
         stake_amount: u64,
     ) -> Result<()> {
-        ctx.accounts
-            .place_prediction(prediction, stake_amount, &ctx.bumps)
+        let event = ctx
+            .accounts
+            .place_prediction(prediction, stake_amount, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn resolve_market(ctx: Context<ResolveMarket>, resolution: i64) -> Result<()> {
+        let (attestation, resolved) = ctx.accounts.resolve_market(resolution)?;
+        if let Some(event) = attestation {
+            emit_cpi!(event);
+        }
+        if let Some(event) = resolved {
+            emit_cpi!(event);
+        }
+        Ok(())
+    }
+
+    pub fn amend_resolution(ctx: Context<AmendResolution>, resolution: i64) -> Result<()> {
+        let event = ctx.accounts.amend_resolution(resolution)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn dispute_resolution(
+        ctx: Context<DisputeResolution>,
+        proposed_resolution: i64,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .dispute_resolution(proposed_resolution, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
-    pub fn resolve_market(
-        ctx: Context<ResolveMarket>,
-        resolution: i64,
-        total_scores: u128,
+    pub fn settle_dispute(ctx: Context<SettleDispute>, overturn: bool) -> Result<()> {
+        let event = ctx.accounts.settle_dispute(overturn)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn propose_resolution(ctx: Context<ProposeResolution>, resolution: i64) -> Result<()> {
+        let event = ctx.accounts.propose_resolution(resolution)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn challenge_resolution(ctx: Context<ChallengeResolution>, resolution: i64) -> Result<()> {
+        let event = ctx.accounts.challenge_resolution(resolution)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn finalize_resolution(ctx: Context<FinalizeResolution>) -> Result<()> {
+        let event = ctx.accounts.finalize_resolution()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn settle_resolution_challenge(
+        ctx: Context<SettleResolutionChallenge>,
+        challenge_succeeds: bool,
+    ) -> Result<()> {
+        let (resolved, settled) = ctx
+            .accounts
+            .settle_resolution_challenge(challenge_succeeds)?;
+        emit_cpi!(resolved);
+        emit_cpi!(settled);
+        Ok(())
+    }
+
+    pub fn initialize_price_samples(ctx: Context<InitializePriceSamples>) -> Result<()> {
+        let event = ctx.accounts.initialize_price_samples(&ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn push_price_sample(ctx: Context<PushPriceSample>) -> Result<()> {
+        let event = ctx.accounts.push_price_sample()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn resolve_from_oracle(ctx: Context<ResolveFromOracle>) -> Result<()> {
+        let event = ctx.accounts.resolve_from_oracle()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn settle_positions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettlePositions<'info>>,
     ) -> Result<()> {
-        ctx.accounts.resolve_market(resolution, total_scores)
+        let event = ctx.accounts.settle_positions(ctx.remaining_accounts)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn post_settlement_root(
+        ctx: Context<PostSettlementRoot>,
+        root: [u8; 32],
+        total_payout: u64,
+    ) -> Result<()> {
+        let event = ctx.accounts.post_settlement_root(root, total_payout)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
-        ctx.accounts.claim_reward()
+        let event = ctx.accounts.claim_reward()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn claim_settled_reward(
+        ctx: Context<ClaimSettledReward>,
+        reward: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let event = ctx.accounts.claim_settled_reward(reward, proof)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn void_market(ctx: Context<VoidMarket>, refund_policy: FeeRefundPolicy) -> Result<()> {
+        let event = ctx.accounts.void_market(refund_policy)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn expire_market(ctx: Context<ExpireMarket>) -> Result<()> {
+        let event = ctx.accounts.expire_market()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn return_bonds(ctx: Context<ReturnBonds>) -> Result<()> {
+        let event = ctx.accounts.return_bonds()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn return_dispute_bond(ctx: Context<ReturnDisputeBond>) -> Result<()> {
+        let event = ctx.accounts.return_dispute_bond()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn refund_position(ctx: Context<RefundPosition>) -> Result<()> {
+        let event = ctx.accounts.refund_position()?;
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn withdraw_creator_revenue(ctx: Context<WithdrawCreatorRevenue>) -> Result<()> {
-        ctx.accounts.withdraw_creator_revenue()
+        let event = ctx.accounts.withdraw_creator_revenue()?;
+        emit_cpi!(event);
+        Ok(())
     }
 }
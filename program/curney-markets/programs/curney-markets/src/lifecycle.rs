@@ -0,0 +1,90 @@
+//! The market lifecycle as a single state machine.
+//!
+//! `Proposed`, `Resolved`, `Disputed`, `Settled`, `Voided`, `Dismissed` and `Withdrawn` are
+//! recorded by the instructions that cause them, while `Trading` and `Closed` follow from the
+//! market schedule once it is approved. Every status change goes through
+//! `MarketState::transition`, which only allows the edges in `MarketStatus::can_transition_to`.
+
+use anchor_lang::prelude::*;
+
+use crate::error::MarketError;
+use crate::state::{MarketConfig, MarketState, MarketStatus, PlatformConfig};
+
+impl MarketStatus {
+    /// Whether a market may move from this status to `next`.
+    pub fn can_transition_to(self, next: MarketStatus) -> bool {
+        use MarketStatus::*;
+
+        matches!(
+            (self, next),
+            (Proposed, Approved | Dismissed | Withdrawn)
+                | (Approved, Trading | Voided)
+                | (Trading, Closed | Voided)
+                | (Closed, Resolved | Voided)
+                | (Resolved, Disputed | Settled)
+                | (Disputed, Resolved)
+                // A market whose settlement stalls past its grace period expires
+                | (Resolved | Disputed, Voided)
+                // A resolution amended before any claim discards the settlement
+                | (Settled, Resolved)
+        )
+    }
+}
+
+impl MarketState {
+    /// Status of the market at `now`, moving an approved market through trading and closed
+    /// according to its start and end time.
+    pub fn status_at(&self, market_config: &MarketConfig, now: i64) -> MarketStatus {
+        match self.status {
+            MarketStatus::Approved | MarketStatus::Trading if now >= market_config.end_time => {
+                MarketStatus::Closed
+            }
+            MarketStatus::Approved if now >= market_config.start_time => MarketStatus::Trading,
+            status => status,
+        }
+    }
+
+    /// Time from which a resolved or disputed market whose settlement stalled can be expired:
+    /// the end of its dispute window plus the platform's settlement grace period.
+    pub fn settlement_deadline(&self, platform_config: &PlatformConfig) -> i64 {
+        self.dispute_ends_at
+            .saturating_add(platform_config.settlement_grace_period)
+    }
+
+    /// Fails unless the market is currently in one of the `expected` statuses, and returns
+    /// the current one.
+    pub fn require_status(
+        &self,
+        market_config: &MarketConfig,
+        now: i64,
+        expected: &[MarketStatus],
+    ) -> Result<MarketStatus> {
+        let status = self.status_at(market_config, now);
+
+        if !expected.contains(&status) {
+            msg!("Market is {:?}, expected {:?}", status, expected);
+            return err!(MarketError::InvalidMarketStatus);
+        }
+
+        Ok(status)
+    }
+
+    /// Moves the market to `next`, provided the lifecycle allows it from the current status.
+    pub fn transition(
+        &mut self,
+        market_config: &MarketConfig,
+        now: i64,
+        next: MarketStatus,
+    ) -> Result<()> {
+        let status = self.status_at(market_config, now);
+
+        if !status.can_transition_to(next) {
+            msg!("Market cannot move from {:?} to {:?}", status, next);
+            return err!(MarketError::InvalidStatusTransition);
+        }
+
+        self.status = next;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+//! Merkle commitments for root-based settlement.
+//!
+//! A resolver commits to every `(position, reward)` pair with a single root posted through
+//! `post_settlement_root`, and each holder later proves their leaf in `claim_settled_reward`.
+//! Leaves and nodes are domain-separated and pairs are hashed in sorted order, so proofs are
+//! plain lists of sibling hashes with no direction bits.
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+#[cfg(not(target_os = "solana"))]
+use crate::scoring::{calculate_reward, calculate_score, calculate_weighted_score};
+#[cfg(not(target_os = "solana"))]
+use crate::state::{MarketConfig, Position};
+
+const LEAF_PREFIX: &[u8] = &[0];
+
+const NODE_PREFIX: &[u8] = &[1];
+
+pub fn settlement_leaf(position: &Pubkey, reward: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, position.as_ref(), &reward.to_le_bytes()]).to_bytes()
+}
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    &computed == root
+}
+
+/// Computes every position's payout for a resolved market, exactly as the on-chain crank
+/// would, returning `(position, reward)` pairs ready for `SettlementTree::new`.
+#[cfg(not(target_os = "solana"))]
+pub fn settlement_rewards(
+    market_config: &MarketConfig,
+    resolution: i64,
+    total_pool: u64,
+    positions: &[(Pubkey, Position)],
+) -> Result<Vec<(Pubkey, u64)>> {
+    let scores = positions
+        .iter()
+        .map(|(_, position)| {
+            calculate_weighted_score(
+                calculate_score(
+                    market_config.scoring_curve,
+                    position.prediction,
+                    resolution,
+                    position.sigma,
+                )?,
+                position.stake,
+                market_config.stake_weighting,
+            )
+        })
+        .collect::<Result<Vec<u128>>>()?;
+
+    let total_scores = scores.iter().sum::<u128>();
+
+    positions
+        .iter()
+        .zip(scores)
+        .map(|((key, _), score)| Ok((*key, calculate_reward(score, total_pool, total_scores)?)))
+        .collect()
+}
+
+/// Off-chain builder for the settlement tree. An odd node at the end of a layer is carried
+/// up unchanged.
+#[cfg(not(target_os = "solana"))]
+pub struct SettlementTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+#[cfg(not(target_os = "solana"))]
+impl SettlementTree {
+    pub fn new(rewards: &[(Pubkey, u64)]) -> Self {
+        let mut layers = vec![rewards
+            .iter()
+            .map(|(position, reward)| settlement_leaf(position, *reward))
+            .collect::<Vec<_>>()];
+
+        while layers.last().is_some_and(|layer| layer.len() > 1) {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers
+            .last()
+            .and_then(|layer| layer.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the sibling hashes proving the leaf at `index`.
+    pub fn proof(&self, mut index: usize) -> Vec<[u8; 32]> {
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewards(count: u64) -> Vec<(Pubkey, u64)> {
+        (0..count)
+            .map(|i| (Pubkey::new_unique(), 1_000 * (i + 1)))
+            .collect()
+    }
+
+    fn assert_every_proof_verifies(rewards: &[(Pubkey, u64)]) {
+        let tree = SettlementTree::new(rewards);
+        let root = tree.root();
+
+        for (index, (position, reward)) in rewards.iter().enumerate() {
+            let leaf = settlement_leaf(position, *reward);
+            assert!(
+                verify_proof(&root, leaf, &tree.proof(index)),
+                "leaf {index} of {} failed to verify",
+                rewards.len()
+            );
+        }
+    }
+
+    #[test]
+    fn proofs_verify_for_even_and_odd_trees() {
+        for count in 2..=9 {
+            assert_every_proof_verifies(&rewards(count));
+        }
+    }
+
+    #[test]
+    fn single_leaf_is_its_own_root() {
+        let rewards = rewards(1);
+        let tree = SettlementTree::new(&rewards);
+
+        assert_eq!(tree.root(), settlement_leaf(&rewards[0].0, rewards[0].1));
+        assert!(tree.proof(0).is_empty());
+        assert_every_proof_verifies(&rewards);
+    }
+
+    #[test]
+    fn odd_leaf_is_carried_up_unchanged() {
+        let rewards = rewards(3);
+        let tree = SettlementTree::new(&rewards);
+
+        // The third leaf has no sibling on the first layer and only pairs with the root of
+        // the first two
+        let proof = tree.proof(2);
+        assert_eq!(proof.len(), 1);
+        assert_eq!(
+            proof[0],
+            hash_pair(
+                &settlement_leaf(&rewards[0].0, rewards[0].1),
+                &settlement_leaf(&rewards[1].0, rewards[1].1)
+            )
+        );
+    }
+
+    #[test]
+    fn tampered_claims_are_rejected() {
+        let rewards = rewards(5);
+        let tree = SettlementTree::new(&rewards);
+        let root = tree.root();
+        let (position, reward) = rewards[3];
+
+        let inflated = settlement_leaf(&position, reward + 1);
+        assert!(!verify_proof(&root, inflated, &tree.proof(3)));
+
+        let stranger = settlement_leaf(&Pubkey::new_unique(), reward);
+        assert!(!verify_proof(&root, stranger, &tree.proof(3)));
+
+        let wrong_proof = tree.proof(1);
+        assert!(!verify_proof(
+            &root,
+            settlement_leaf(&position, reward),
+            &wrong_proof
+        ));
+    }
+}
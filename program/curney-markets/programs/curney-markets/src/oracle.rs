@@ -0,0 +1,125 @@
+//! Price resolution from Pyth `PriceUpdateV2` accounts.
+//!
+//! Only the fields needed to resolve a market are read, straight from the account data, so
+//! the program does not depend on the Pyth SDK. Markets name a feed ID rather than an
+//! account, so any update for that feed owned by the Pyth receiver program can be used.
+
+use anchor_lang::prelude::*;
+
+use crate::error::OracleError;
+
+/// Owner of every `PriceUpdateV2` account
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of `PriceUpdateV2`
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Largest exponent difference a price can be rescaled by without overflowing an `i64`
+const MAX_EXPONENT_SHIFT: u32 = 18;
+
+/// The parts of a `PriceUpdateV2` account used for resolution.
+pub struct PriceUpdate {
+    pub fully_verified: bool,
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        require!(self.data.len() >= len, OracleError::InvalidPriceFeed);
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn read<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(self.take(N)?);
+        Ok(bytes)
+    }
+}
+
+impl PriceUpdate {
+    /// Parses a `PriceUpdateV2` account:
+    /// `discriminator | write_authority | verification_level | price_message | posted_slot`,
+    /// where `price_message` starts with
+    /// `feed_id | price | conf | exponent | publish_time`.
+    pub fn try_from_account(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            PYTH_RECEIVER_PROGRAM_ID,
+            OracleError::InvalidPriceFeed
+        );
+
+        let data = account.try_borrow_data()?;
+        let mut reader = Reader { data: &data };
+
+        require!(
+            reader.read::<8>()? == PRICE_UPDATE_V2_DISCRIMINATOR,
+            OracleError::InvalidPriceFeed
+        );
+
+        // Write authority
+        reader.take(32)?;
+
+        // `Partial { num_signatures: u8 }` or `Full`
+        let fully_verified = match reader.read::<1>()?[0] {
+            0 => {
+                reader.take(1)?;
+                false
+            }
+            1 => true,
+            _ => return err!(OracleError::InvalidPriceFeed),
+        };
+
+        let feed_id = reader.read::<32>()?;
+        let price = i64::from_le_bytes(reader.read()?);
+        // Confidence interval
+        reader.take(8)?;
+        let exponent = i32::from_le_bytes(reader.read()?);
+        let publish_time = i64::from_le_bytes(reader.read()?);
+
+        Ok(Self {
+            fully_verified,
+            feed_id,
+            price,
+            exponent,
+            publish_time,
+        })
+    }
+}
+
+/// Rescales `price * 10^exponent` to an integer in units of `10^target_exponent`, rounding
+/// half away from zero when precision is dropped.
+pub fn scale_price(price: i64, exponent: i32, target_exponent: i32) -> Result<i64> {
+    let shift = exponent
+        .checked_sub(target_exponent)
+        .ok_or(OracleError::InvalidExponent)?;
+
+    require!(
+        shift.unsigned_abs() <= MAX_EXPONENT_SHIFT,
+        OracleError::InvalidExponent
+    );
+
+    let factor = 10i128.pow(shift.unsigned_abs());
+    let price = price as i128;
+
+    let scaled = if shift >= 0 {
+        price * factor
+    } else {
+        let half = factor / 2;
+        if price >= 0 {
+            (price + half) / factor
+        } else {
+            (price - half) / factor
+        }
+    };
+
+    i64::try_from(scaled).map_err(|_| error!(OracleError::PriceOutOfRange))
+}
@@ -0,0 +1,45 @@
+//! Platform roles.
+//!
+//! The admin is the super-admin: it grants and revokes every other role, each held by a
+//! single key on `PlatformConfig`. Every role starts with the admin, and a revoked role goes
+//! back to it, so a role is never left without a holder. Roles the admin still holds move to
+//! the new admin on an admin transfer.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{PlatformConfig, PlatformRole};
+
+impl PlatformRole {
+    pub const ALL: [PlatformRole; 5] = [
+        PlatformRole::Approver,
+        PlatformRole::Resolver,
+        PlatformRole::Treasurer,
+        PlatformRole::Pauser,
+        PlatformRole::Arbiter,
+    ];
+}
+
+impl PlatformConfig {
+    /// Holder of `role`.
+    pub fn role_holder(&self, role: PlatformRole) -> Pubkey {
+        match role {
+            PlatformRole::Approver => self.approver,
+            PlatformRole::Resolver => self.resolver,
+            PlatformRole::Treasurer => self.treasurer,
+            PlatformRole::Pauser => self.pauser,
+            PlatformRole::Arbiter => self.arbiter,
+        }
+    }
+
+    /// Hands `role` to `holder`.
+    pub fn set_role_holder(&mut self, role: PlatformRole, holder: Pubkey) {
+        let slot = match role {
+            PlatformRole::Approver => &mut self.approver,
+            PlatformRole::Resolver => &mut self.resolver,
+            PlatformRole::Treasurer => &mut self.treasurer,
+            PlatformRole::Pauser => &mut self.pauser,
+            PlatformRole::Arbiter => &mut self.arbiter,
+        };
+        *slot = holder;
+    }
+}
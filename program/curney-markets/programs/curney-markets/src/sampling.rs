@@ -0,0 +1,123 @@
+//! Aggregation of the price samples pushed during a market's sampling window.
+//!
+//! Keepers push verified feed prices into a bounded ring buffer through `push_price_sample`,
+//! and `resolve_from_oracle` reduces them to a single resolution. Resolving from a window of
+//! samples rather than one price makes the outcome much harder to move with a single print.
+
+use anchor_lang::prelude::*;
+
+use crate::error::{MarketError, OracleError};
+use crate::state::{PriceSample, PriceSampling, SampleAggregation};
+
+/// Reduces `samples` to a single price, failing until the buffer holds the market's minimum
+/// number of samples so a keeper cannot resolve it from a handful of early prints.
+pub fn aggregate_samples(
+    samples: &[PriceSample],
+    sampling: PriceSampling,
+    window_end: i64,
+) -> Result<i64> {
+    require!(!samples.is_empty(), OracleError::NoPriceSamples);
+    require!(
+        samples.len() >= sampling.min_samples as usize,
+        OracleError::NotEnoughSamples
+    );
+
+    match sampling.aggregation {
+        SampleAggregation::TimeWeightedAverage => time_weighted_average(samples, window_end),
+        SampleAggregation::Median => {
+            let mut prices: Vec<i64> = samples.iter().map(|s| s.price).collect();
+            prices.sort_unstable();
+            Ok(prices[(prices.len() - 1) / 2])
+        }
+    }
+}
+
+/// Weights each sample by the time until the next sample, or until `window_end` for the last
+/// one. Falls back to the plain average when every sample shares the same publish time.
+fn time_weighted_average(samples: &[PriceSample], window_end: i64) -> Result<i64> {
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable_by_key(|s| s.publish_time);
+
+    let mut weighted_sum: i128 = 0;
+    let mut total_weight: i128 = 0;
+
+    for (i, sample) in sorted.iter().enumerate() {
+        let until = sorted
+            .get(i + 1)
+            .map_or(window_end, |next| next.publish_time);
+        let weight = until.saturating_sub(sample.publish_time).max(0) as i128;
+
+        weighted_sum = weighted_sum
+            .checked_add(sample.price as i128 * weight)
+            .ok_or(MarketError::MathOverflow)?;
+        total_weight += weight;
+    }
+
+    if total_weight == 0 {
+        weighted_sum = sorted.iter().map(|s| s.price as i128).sum();
+        total_weight = sorted.len() as i128;
+    }
+
+    // Round half away from zero
+    let half = total_weight / 2;
+    let average = if weighted_sum >= 0 {
+        (weighted_sum + half) / total_weight
+    } else {
+        (weighted_sum - half) / total_weight
+    };
+
+    i64::try_from(average).map_err(|_| error!(OracleError::PriceOutOfRange))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sampling(min_samples: u16, aggregation: SampleAggregation) -> PriceSampling {
+        PriceSampling {
+            window: 300,
+            min_interval: 60,
+            min_samples,
+            aggregation,
+        }
+    }
+
+    fn samples(prices: &[i64]) -> Vec<PriceSample> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(i, price)| PriceSample {
+                publish_time: 1_000 + 60 * i as i64,
+                price: *price,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rejects_fewer_samples_than_required() {
+        let samples = samples(&[100, 101]);
+
+        assert!(
+            aggregate_samples(&samples, sampling(3, SampleAggregation::Median), 1_300).is_err()
+        );
+        assert!(aggregate_samples(&[], sampling(1, SampleAggregation::Median), 1_300).is_err());
+        assert_eq!(
+            aggregate_samples(&samples, sampling(2, SampleAggregation::Median), 1_300).unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn weights_the_last_sample_until_the_window_end() {
+        // 100 holds for 60 seconds, 110 for the remaining 240
+        let samples = samples(&[100, 110]);
+        let twap = aggregate_samples(
+            &samples,
+            sampling(2, SampleAggregation::TimeWeightedAverage),
+            1_300,
+        )
+        .unwrap();
+
+        assert_eq!(twap, 108);
+    }
+}
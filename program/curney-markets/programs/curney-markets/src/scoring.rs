@@ -0,0 +1,464 @@
+//! Deterministic, integer-only scoring shared by the program and off-chain tools.
+//!
+//! Everything here is pure fixed-point arithmetic, so an indexer or resolver that links
+//! this crate (with the `no-entrypoint` feature) computes bit-for-bit the same scores and
+//! rewards that `settle_positions` and `claim_reward` use on-chain.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::FIXED_POINT_SCALE;
+use crate::error::{MarketError, PositionError};
+use crate::state::{ScoringCurve, StakeWeighting};
+
+/// Internal working precision, `FIXED_POINT_SCALE^2` (1e18).
+const WAD: u128 = (FIXED_POINT_SCALE as u128) * (FIXED_POINT_SCALE as u128);
+
+/// `ln(2)` scaled by `WAD`.
+const LN_2_WAD: u128 = 693_147_180_559_945_309;
+
+/// Beyond an exponent of 22, `e^{-y}` is below half a unit of `FIXED_POINT_SCALE` and
+/// rounds to zero.
+const MAX_EXPONENT_WAD: u128 = 22 * WAD;
+
+/// Number of Taylor terms for `e^{-r}` with `r` in `[0, ln 2)`; the first omitted term is
+/// below `2e-19`.
+const EXP_TAYLOR_TERMS: u128 = 18;
+
+/// Computes `e^{-y}` for `y` in `WAD` units, returning a `WAD`-scaled result.
+///
+/// Uses `e^{-y} = 2^{-k} * e^{-r}` with `y = k ln 2 + r`, evaluating `e^{-r}` by its Taylor
+/// series. The absolute error is below `4e-17`, far under the final `FIXED_POINT_SCALE`
+/// rounding. Inputs past `MAX_EXPONENT_WAD` saturate to zero.
+pub fn exp_neg_wad(y: u128) -> u128 {
+    if y >= MAX_EXPONENT_WAD {
+        return 0;
+    }
+
+    let k = y / LN_2_WAD;
+    let r = y - k * LN_2_WAD;
+
+    let mut sum = WAD;
+    let mut term = WAD;
+    for n in 1..=EXP_TAYLOR_TERMS {
+        term = term * r / (n * WAD);
+        if n % 2 == 1 {
+            sum -= term;
+        } else {
+            sum += term;
+        }
+    }
+
+    sum >> k
+}
+
+/// Returns the normalized distance `x` at or beyond which `curve` scores zero after
+/// rounding to `FIXED_POINT_SCALE`.
+fn saturation_distance(curve: ScoringCurve) -> u128 {
+    match curve {
+        // e^{-25} < 0.5e-9
+        ScoringCurve::Gaussian => 5,
+        // e^{-22} < 0.5e-9
+        ScoringCurve::Laplace => 22,
+        // 1 / (1 + 44_722^2) < 0.5e-9
+        ScoringCurve::Cauchy => 44_722,
+        ScoringCurve::Tent => 1,
+    }
+}
+
+/// Returns `numerator / denominator` scaled by `WAD`, or `None` when the quotient reaches
+/// `saturation`, past which every score is zero.
+fn ratio_wad(numerator: u64, denominator: u64, saturation: u128) -> Option<u128> {
+    let (numerator, denominator) = (numerator as u128, denominator as u128);
+
+    let quotient = numerator / denominator;
+    if quotient >= saturation {
+        return None;
+    }
+
+    let remainder = numerator % denominator;
+    Some(quotient * WAD + remainder * WAD / denominator)
+}
+
+/// Returns `floor(x^2 / WAD)` without overflowing for any `x` below `saturation_distance`.
+fn square_wad(x: u128) -> u128 {
+    let whole = x / WAD;
+    let fraction = x % WAD;
+    whole * whole * WAD + 2 * whole * fraction + fraction * fraction / WAD
+}
+
+/// Evaluates `curve` at the normalized distance `x`, both scaled by `WAD`.
+fn curve_wad(curve: ScoringCurve, x: u128) -> u128 {
+    match curve {
+        ScoringCurve::Gaussian => exp_neg_wad(square_wad(x)),
+        ScoringCurve::Laplace => exp_neg_wad(x),
+        ScoringCurve::Cauchy => WAD * WAD / (WAD + square_wad(x)),
+        ScoringCurve::Tent => WAD.saturating_sub(x),
+    }
+}
+
+/// Computes the sigma in effect at `now` for a market running from `start_time` to
+/// `end_time`, following `sigma(t) = sigma_min + (sigma_max - sigma_min)(1 - t/T)`.
+///
+/// The result depends only on the timestamp and never drops below `sigma_min`.
+pub fn calculate_sigma(
+    sigma_min: u64,
+    sigma_max: u64,
+    start_time: i64,
+    end_time: i64,
+    now: i64,
+) -> Result<u64> {
+    require!(
+        sigma_min > 0 && sigma_min <= sigma_max,
+        MarketError::InvalidSigmaRange
+    );
+    require!(end_time > start_time, MarketError::InvalidEndTime);
+
+    let duration = end_time.abs_diff(start_time);
+    let remaining = end_time
+        .saturating_sub(now)
+        .max(0)
+        .unsigned_abs()
+        .min(duration);
+    let spread = sigma_max - sigma_min;
+
+    let sigma = sigma_min as u128 + spread as u128 * remaining as u128 / duration as u128;
+
+    Ok(sigma as u64)
+}
+
+/// Computes the score of a prediction on the market's `curve`, scaled by
+/// `FIXED_POINT_SCALE`. The curve is evaluated at `x`, the distance between prediction and
+/// resolution divided by the position's sigma.
+///
+/// The result is rounded to the nearest unit and is within one unit of the exact curve
+/// value. Distances far outside the curve saturate to zero instead of overflowing.
+pub fn calculate_score(
+    curve: ScoringCurve,
+    prediction: i64,
+    resolution: i64,
+    sigma: u64,
+) -> Result<u128> {
+    require!(sigma > 0, PositionError::InvalidSigma);
+
+    let dist = prediction.abs_diff(resolution);
+
+    let x = match ratio_wad(dist, sigma, saturation_distance(curve)) {
+        Some(x) => x,
+        None => return Ok(0),
+    };
+
+    let unit = WAD / FIXED_POINT_SCALE as u128;
+    let score = (curve_wad(curve, x) + unit / 2) / unit;
+
+    Ok(score)
+}
+
+/// Weights a score by the position's stake under `stake_weighting`.
+///
+/// Scores never exceed `FIXED_POINT_SCALE` and weights never exceed a `u64` stake, so a
+/// weighted score, and the market's running total of them, always fits in a `u128`.
+pub fn calculate_weighted_score(
+    score: u128,
+    stake: u64,
+    stake_weighting: StakeWeighting,
+) -> Result<u128> {
+    let weight = match stake_weighting {
+        StakeWeighting::Unweighted => 1,
+        StakeWeighting::Linear => stake,
+        StakeWeighting::SquareRoot => stake.isqrt(),
+    };
+
+    let weighted_score = score
+        .checked_mul(weight as u128)
+        .ok_or(MarketError::MathOverflow)?;
+
+    Ok(weighted_score)
+}
+
+// Every stake weighting and mint supply keeps the summed weighted scores within a `u128`.
+const _: () = assert!((FIXED_POINT_SCALE as u128)
+    .checked_mul(u64::MAX as u128)
+    .is_some());
+
+/// Computes a position's share of `total_pool`, `score * total_pool / total_scores`.
+///
+/// The product is carried in 256 bits, so linearly weighted scores on high-supply mints
+/// never overflow.
+pub fn calculate_reward(score: u128, total_pool: u64, total_scores: u128) -> Result<u64> {
+    if total_scores == 0 {
+        return Ok(0);
+    };
+
+    let reward =
+        mul_div(score, total_pool as u128, total_scores).ok_or(MarketError::MathOverflow)?;
+
+    u64::try_from(reward).map_err(|_| error!(MarketError::MathOverflow))
+}
+
+/// Returns `floor(a * b / denominator)` with a 256-bit intermediate product, or `None` when
+/// the quotient does not fit in a `u128`.
+fn mul_div(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    const LOW: u128 = u64::MAX as u128;
+
+    let (a_hi, a_lo) = (a >> 64, a & LOW);
+    let (b_hi, b_lo) = (b >> 64, b & LOW);
+
+    let low = a_lo * b_lo;
+    let cross_a = a_hi * b_lo;
+    let cross_b = a_lo * b_hi;
+    let middle = (low >> 64) + (cross_a & LOW) + (cross_b & LOW);
+
+    let product_lo = (low & LOW) | (middle << 64);
+    let product_hi = a_hi * b_hi + (cross_a >> 64) + (cross_b >> 64) + (middle >> 64);
+
+    if product_hi >= denominator {
+        return None;
+    }
+
+    // Schoolbook long division of the low half, carrying the high half as the remainder
+    let mut remainder = product_hi;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((product_lo >> bit) & 1);
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1 << bit;
+        }
+    }
+
+    Some(quotient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Absolute error bound of `exp_neg_wad`, in `WAD` units.
+    const EXP_TOLERANCE: u128 = 40;
+
+    #[test]
+    fn exp_neg_wad_matches_known_values() {
+        // e^{-y} to 18 decimals, rounded to nearest
+        let cases = [
+            (0, WAD),
+            (1_000_000_000, 999_999_999_000_000_000),
+            (WAD / 2, 606_530_659_712_633_424),
+            (LN_2_WAD, 500_000_000_000_000_000),
+            (WAD, 367_879_441_171_442_322),
+            (2 * WAD, 135_335_283_236_612_692),
+            (5 * WAD, 6_737_946_999_085_467),
+            (10 * WAD, 45_399_929_762_485),
+            (15 * WAD + WAD / 2, 185_539_136_262),
+            (21 * WAD, 758_256_043),
+            (MAX_EXPONENT_WAD - 1_000_000_000, 278_946_810),
+        ];
+        for (y, expected) in cases {
+            let actual = exp_neg_wad(y);
+            assert!(
+                actual.abs_diff(expected) <= EXP_TOLERANCE,
+                "e^-{y}: got {actual}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn exp_neg_wad_saturates_at_max_exponent() {
+        assert!(exp_neg_wad(MAX_EXPONENT_WAD - 1) > 0);
+        assert_eq!(exp_neg_wad(MAX_EXPONENT_WAD), 0);
+        assert_eq!(exp_neg_wad(MAX_EXPONENT_WAD + 1), 0);
+        assert_eq!(exp_neg_wad(u128::MAX), 0);
+
+        // Just below saturation the result already rounds to zero at FIXED_POINT_SCALE
+        let unit = WAD / FIXED_POINT_SCALE as u128;
+        assert!(exp_neg_wad(MAX_EXPONENT_WAD - 1) < unit / 2);
+    }
+
+    #[test]
+    fn exp_neg_wad_is_monotonically_decreasing() {
+        // A stride that is not a multiple of ln 2 lands on every offset within an octave
+        let stride = WAD / 1_000 + 7;
+
+        let mut previous = exp_neg_wad(0);
+        let mut y = stride;
+        while y < MAX_EXPONENT_WAD {
+            let current = exp_neg_wad(y);
+            assert!(current <= previous, "e^-y rose at y = {y}");
+            previous = current;
+            y += stride;
+        }
+
+        // Both sides of every octave boundary, where the series restarts
+        for k in 1..(MAX_EXPONENT_WAD / LN_2_WAD) {
+            let boundary = k * LN_2_WAD;
+            assert!(exp_neg_wad(boundary) <= exp_neg_wad(boundary - 1));
+            assert!(exp_neg_wad(boundary + 1) <= exp_neg_wad(boundary));
+        }
+    }
+
+    #[test]
+    fn curves_match_known_values() {
+        // (curve, distance, sigma, score) with the score rounded to FIXED_POINT_SCALE
+        let cases = [
+            (ScoringCurve::Gaussian, 0, 4, 1_000_000_000),
+            (ScoringCurve::Gaussian, 2, 4, 778_800_783),
+            (ScoringCurve::Gaussian, 4, 4, 367_879_441),
+            (ScoringCurve::Laplace, 0, 4, 1_000_000_000),
+            (ScoringCurve::Laplace, 2, 4, 606_530_660),
+            (ScoringCurve::Laplace, 4, 4, 367_879_441),
+            (ScoringCurve::Laplace, 8, 4, 135_335_283),
+            (ScoringCurve::Cauchy, 0, 4, 1_000_000_000),
+            (ScoringCurve::Cauchy, 2, 4, 800_000_000),
+            (ScoringCurve::Cauchy, 4, 4, 500_000_000),
+            (ScoringCurve::Cauchy, 8, 4, 200_000_000),
+            (ScoringCurve::Cauchy, 12, 4, 100_000_000),
+            (ScoringCurve::Tent, 0, 4, 1_000_000_000),
+            (ScoringCurve::Tent, 1, 4, 750_000_000),
+            (ScoringCurve::Tent, 2, 4, 500_000_000),
+            (ScoringCurve::Tent, 4, 4, 0),
+            (ScoringCurve::Tent, 8, 4, 0),
+        ];
+        for (curve, dist, sigma, expected) in cases {
+            assert_eq!(
+                calculate_score(curve, 100 + dist, 100, sigma).unwrap(),
+                expected,
+                "distance {dist}, sigma {sigma}"
+            );
+            // Predictions on either side of the resolution score the same
+            assert_eq!(
+                calculate_score(curve, 100 - dist, 100, sigma).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn saturation_distance_only_cuts_off_zero_scores() {
+        let sigma = 1_000_000;
+        for curve in [
+            ScoringCurve::Gaussian,
+            ScoringCurve::Laplace,
+            ScoringCurve::Cauchy,
+            ScoringCurve::Tent,
+        ] {
+            let cutoff = saturation_distance(curve) as i64 * sigma as i64;
+            assert_eq!(calculate_score(curve, cutoff, 0, sigma).unwrap(), 0);
+            assert_eq!(
+                calculate_score(curve, i64::MAX, i64::MIN, sigma).unwrap(),
+                0
+            );
+
+            // Just inside the cutoff the curve is evaluated, and rounds to zero anyway
+            // except on the tent, which reaches zero only at the cutoff
+            let inside = calculate_score(curve, cutoff - 1, 0, sigma).unwrap();
+            let expected = match curve {
+                ScoringCurve::Tent => 1_000,
+                _ => 0,
+            };
+            assert_eq!(inside, expected, "cutoff {}", saturation_distance(curve));
+        }
+
+        // The Laplace and Cauchy cutoffs are tight: one sigma closer still scores a unit
+        let laplace = (saturation_distance(ScoringCurve::Laplace) - 1) as i64;
+        assert_eq!(
+            calculate_score(ScoringCurve::Laplace, laplace, 0, 1).unwrap(),
+            1
+        );
+        let cauchy = (saturation_distance(ScoringCurve::Cauchy) - 1) as i64;
+        assert_eq!(
+            calculate_score(ScoringCurve::Cauchy, cauchy, 0, 1).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn stake_weightings_match_known_values() {
+        let score = 500_000_000;
+        let cases = [
+            (StakeWeighting::Unweighted, 1_000_000, score),
+            (StakeWeighting::Linear, 1_000_000, score * 1_000_000),
+            (StakeWeighting::SquareRoot, 1_000_000, score * 1_000),
+            // The square root rounds down
+            (StakeWeighting::SquareRoot, 99, score * 9),
+            (
+                StakeWeighting::SquareRoot,
+                u64::MAX,
+                score * u32::MAX as u128,
+            ),
+            (StakeWeighting::Unweighted, 0, score),
+            (StakeWeighting::Linear, 0, 0),
+        ];
+        for (stake_weighting, stake, expected) in cases {
+            assert_eq!(
+                calculate_weighted_score(score, stake, stake_weighting).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn sigma_narrows_linearly_over_the_market() {
+        // (now, sigma) for a market from 1_000 to 1_100 with sigma from 10 to 110
+        let cases = [
+            (900, 110),
+            (1_000, 110),
+            (1_033, 77),
+            (1_050, 60),
+            (1_099, 11),
+            (1_100, 10),
+            (2_000, 10),
+        ];
+        for (now, expected) in cases {
+            assert_eq!(
+                calculate_sigma(10, 110, 1_000, 1_100, now).unwrap(),
+                expected,
+                "at {now}"
+            );
+        }
+
+        // A fixed sigma stays put
+        assert_eq!(calculate_sigma(25, 25, 1_000, 1_100, 1_050).unwrap(), 25);
+
+        assert!(calculate_sigma(0, 110, 1_000, 1_100, 1_050).is_err());
+        assert!(calculate_sigma(111, 110, 1_000, 1_100, 1_050).is_err());
+        assert!(calculate_sigma(10, 110, 1_100, 1_100, 1_050).is_err());
+    }
+
+    #[test]
+    fn mul_div_matches_narrow_arithmetic() {
+        let cases = [
+            (0, 0, 1),
+            (7, 9, 4),
+            (u64::MAX as u128, u64::MAX as u128, 3),
+            (123_456_789_012_345, 987_654_321, 1_000_003),
+        ];
+        for (a, b, denominator) in cases {
+            assert_eq!(mul_div(a, b, denominator), Some(a * b / denominator));
+        }
+    }
+
+    #[test]
+    fn mul_div_carries_wide_products() {
+        assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(mul_div(u128::MAX, 2, 4), Some(u128::MAX / 2));
+        assert_eq!(mul_div(1 << 100, 1 << 100, 1 << 90), Some(1 << 110));
+        assert_eq!(mul_div(u128::MAX, 2, 1), None);
+    }
+
+    #[test]
+    fn linear_rewards_do_not_overflow_on_high_supply_mints() {
+        let stake = u64::MAX / 4;
+        let total_pool = u64::MAX;
+
+        let score =
+            calculate_weighted_score(FIXED_POINT_SCALE as u128, stake, StakeWeighting::Linear)
+                .unwrap();
+        let total_scores = score * 4;
+
+        let reward = calculate_reward(score, total_pool, total_scores).unwrap();
+        assert_eq!(reward, total_pool / 4);
+
+        let everything = calculate_reward(total_scores, total_pool, total_scores).unwrap();
+        assert_eq!(everything, total_pool);
+    }
+}
@@ -1,16 +1,220 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{MARKET_DESCRIPTION_MAX_LEN, MARKET_QUESTION_MAX_LEN};
+use crate::constants::{
+    MARKET_DESCRIPTION_MAX_LEN, MARKET_QUESTION_MAX_LEN, MAX_COMMITTEE_MEMBERS, MAX_PRICE_SAMPLES,
+};
 
 #[account]
 #[derive(InitSpace)]
 pub struct PlatformConfig {
     pub bump: u8,
     pub treasury_bump: u8,
+    /// Stable ID the config is addressed by, so the admin key can change
+    pub platform_id: u64,
     pub creator_fee_bps: u16,
     pub platform_fee_bps: u16,
     pub market_proposal_fee: u64,
+    /// Share of the proposal fee refunded when a market is dismissed
+    pub dismissal_refund_bps: u16,
+    /// Share of the proposal fee refunded when a market is dismissed as spam
+    pub spam_dismissal_refund_bps: u16,
+    /// Share of the proposal fee refunded when the creator withdraws a proposal
+    pub withdrawal_refund_bps: u16,
+    pub dispute_period: i64,
+    pub dispute_bond: u64,
+    pub dispute_reward_bps: u16,
+    pub resolver_grace_period: i64,
+    /// Time after the dispute window for settling a resolved market before it can be expired
+    pub settlement_grace_period: i64,
+    /// Super-admin, who grants and revokes every other role
     pub admin: Pubkey,
+    /// Admin proposed by the current one, who takes over once they accept
+    pub pending_admin: Option<Pubkey>,
+    /// Approves, dismisses and edits market proposals
+    pub approver: Pubkey,
+    /// Resolves markets, those with a designated resolver once the grace period is over, amends
+    /// resolutions of markets without one, and posts settlement roots
+    pub resolver: Pubkey,
+    /// Withdraws platform fees and updates the fee settings
+    pub treasurer: Pubkey,
+    /// Pauses new proposals, approvals and predictions in an emergency
+    pub pauser: Pubkey,
+    /// Settles disputes and resolution challenges
+    pub arbiter: Pubkey,
+    pub is_paused: bool,
+    /// Key the committee and market PDAs are derived from: the config's own address, or the
+    /// address of the admin-keyed config it was migrated from
+    pub namespace: Pubkey,
+}
+
+/// Layout of a `PlatformConfig` addressed by its admin key, as deployed before platforms had a
+/// stable ID. It is only read by `migrate_platform`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyPlatformConfig {
+    pub bump: u8,
+    pub treasury_bump: u8,
+    pub creator_fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub market_proposal_fee: u64,
+    pub admin: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ResolverCommittee {
+    pub bump: u8,
+    pub threshold: u8,
+    pub tolerance: u64,
+    #[max_len(MAX_COMMITTEE_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    /// Number of times each member's attestation was flagged as an outlier, in member order
+    #[max_len(MAX_COMMITTEE_MEMBERS)]
+    pub flag_counts: Vec<u16>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PlatformRole {
+    Approver,
+    Resolver,
+    Treasurer,
+    Pauser,
+    Arbiter,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct Attestation {
+    pub member: Pubkey,
+    pub value: i64,
+    /// Set at resolution when the value is further than the tolerance from the median
+    pub is_flagged: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum StakeWeighting {
+    /// Every position counts equally, regardless of stake
+    Unweighted,
+    /// Scores are multiplied by the net stake
+    Linear,
+    /// Scores are multiplied by the square root of the net stake
+    SquareRoot,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ScoringCurve {
+    /// `e^{-x^2}`, sharp falloff for questions with a precise answer
+    Gaussian,
+    /// `e^{-|x|}`, exponential falloff with a heavier tail than the Gaussian
+    Laplace,
+    /// `1 / (1 + x^2)`, heavy tail that keeps rewarding distant predictions
+    Cauchy,
+    /// `1 - |x|`, linear falloff reaching zero at one sigma
+    Tent,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SettlementMode {
+    /// Positions are scored on-chain by the `settle_positions` crank
+    OnChain,
+    /// The resolver posts a Merkle root of every position's reward
+    MerkleRoot,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum MarketStatus {
+    /// Awaiting admin approval
+    Proposed,
+    /// Approved but not started
+    Approved,
+    /// Accepting predictions
+    Trading,
+    /// Ended and awaiting resolution
+    Closed,
+    /// Resolved, with the dispute period possibly still running
+    Resolved,
+    /// Resolved with at least one dispute open
+    Disputed,
+    /// Positions settled and rewards claimable
+    Settled,
+    /// Cancelled, stakes are refundable
+    Voided,
+    /// Rejected before approval
+    Dismissed,
+    /// Withdrawn by the creator before approval
+    Withdrawn,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DismissReason {
+    /// The question or resolution criteria are ambiguous
+    Unclear,
+    /// No reliable data source can resolve the market
+    Unresolvable,
+    /// Another market already covers the same question
+    Duplicate,
+    /// The market breaks the platform's content rules
+    Inappropriate,
+    /// Refunded at the spam rate
+    Spam,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum FeeRefundPolicy {
+    /// Only the net stake is refunded, both fees are kept
+    StakeOnly,
+    /// The net stake and the creator fee are refunded, the platform keeps its fee
+    StakeAndCreatorFee,
+    /// The gross stake is refunded, including both fees
+    GrossStake,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SampleAggregation {
+    /// Each sample is weighted by how long it stood before the next one or the end time
+    TimeWeightedAverage,
+    /// The median sample, the lower of the two middle ones for an even count
+    Median,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PriceSampling {
+    /// Length of the sampling window that closes at the end time
+    pub window: i64,
+    /// Minimum publish time gap between two consecutive samples
+    pub min_interval: i64,
+    /// Fewest samples the buffer must hold before the market can resolve
+    pub min_samples: u16,
+    pub aggregation: SampleAggregation,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PriceOracle {
+    /// Pyth feed ID every price update used by the market must carry
+    pub feed_id: [u8; 32],
+    /// Decimal exponent the resolution is expressed in, e.g. `-2` for cents
+    pub exponent: i32,
+    /// Longest delay after the end time at which a published price is still accepted
+    pub max_staleness: i64,
+    /// Resolve from prices sampled before the end time rather than a single price after it
+    pub sampling: Option<PriceSampling>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PriceSample {
+    pub publish_time: i64,
+    /// Price already scaled to the market's exponent
+    pub price: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PriceSamples {
+    pub bump: u8,
+    /// Slot the next sample overwrites once the buffer is full
+    pub next_index: u16,
+    pub last_publish_time: i64,
+    pub market: Pubkey,
+    #[max_len(MAX_PRICE_SAMPLES)]
+    pub samples: Vec<PriceSample>,
 }
 
 #[account]
@@ -21,7 +225,22 @@ pub struct MarketConfig {
     pub market_id: u64,
     pub start_time: i64,
     pub end_time: i64,
+    pub resolution_deadline: i64,
     pub min_prediction_price: u64,
+    pub sigma_min: u64,
+    pub sigma_max: u64,
+    pub stake_weighting: StakeWeighting,
+    pub scoring_curve: ScoringCurve,
+    pub settlement_mode: SettlementMode,
+    pub stake_mint: Option<Pubkey>,
+    pub oracle: Option<PriceOracle>,
+    pub resolver: Option<Pubkey>,
+    /// SOL bond the creator locks to resolve the market optimistically, or zero
+    pub creator_bond: u64,
+    /// Proposal fee the creator paid, escrowed in the market vault until approval or dismissal
+    pub proposal_fee: u64,
+    /// Number of edits made to the proposal, the admin approves a specific revision
+    pub revision: u16,
     #[max_len(MARKET_QUESTION_MAX_LEN)]
     pub question: String,
     #[max_len(MARKET_DESCRIPTION_MAX_LEN)]
@@ -34,14 +253,33 @@ pub struct MarketConfig {
 #[derive(InitSpace)]
 pub struct MarketState {
     pub bump: u8,
-    pub decay: u64,
-    pub is_approved: bool,
-    pub is_resolved: bool,
+    /// Last recorded status, see `MarketState::status_at` for the current one
+    pub status: MarketStatus,
+    pub is_expired: bool,
+    pub has_claims: bool,
     pub resolution: Option<i64>,
+    pub resolution_amendments: u16,
+    pub refund_policy: Option<FeeRefundPolicy>,
+    pub dispute_ends_at: i64,
+    pub open_disputes: u64,
+    #[max_len(MAX_COMMITTEE_MEMBERS)]
+    pub attestations: Vec<Attestation>,
+    pub creator_bond_locked: bool,
+    pub proposed_resolution: Option<i64>,
+    pub proposal_ends_at: i64,
+    pub challenger: Option<Pubkey>,
+    pub challenged_resolution: Option<i64>,
+    pub challenger_bond: u64,
     pub total_pool: u64,
     pub total_positions: u64,
-    pub total_scores: Option<u128>,
+    pub scored_positions: u64,
+    pub total_scores: u128,
+    pub settlement_root: Option<[u8; 32]>,
+    pub settlement_payout: u64,
+    pub claimed_payout: u64,
     pub creator_fee_revenue: u64,
+    /// Platform fees escrowed in the market vault until the market resolves
+    pub platform_fee_revenue: u64,
     pub market_config: Pubkey,
 }
 
@@ -51,11 +289,37 @@ pub struct Position {
     pub claimed: bool,
     pub bump: u8,
     pub stake: u64,
-    pub decay: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub sigma: u64,
     pub index: u64,
+    pub score: Option<u128>,
+    /// Resolution amendment the score was computed against
+    pub scored_amendment: u16,
     pub reward: Option<u64>,
     pub timestamp: i64,
     pub prediction: i64,
     pub user: Pubkey,
     pub market: Pubkey,
 }
+
+/// Left behind by a dismissed market so its ID stays taken
+#[account]
+#[derive(InitSpace)]
+pub struct MarketTombstone {
+    pub bump: u8,
+    pub market_id: u64,
+    pub dismissed_at: i64,
+    pub reason: DismissReason,
+    pub creator: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub bump: u8,
+    pub bond: u64,
+    pub proposed_resolution: i64,
+    pub challenger: Pubkey,
+    pub market: Pubkey,
+}
@@ -0,0 +1,182 @@
+//! Helpers for markets denominated in an SPL Token or Token-2022 mint.
+//!
+//! SOL markets leave every token account out of the instruction. Token markets hold their
+//! stakes in the associated token account of the `market_vault` PDA and their platform fees
+//! in the associated token account of the `platform_treasury` PDA.
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::error::MarketError;
+
+/// Unwraps an optional account that token-denominated markets require.
+pub fn required<T>(account: &Option<T>) -> Result<&T> {
+    account
+        .as_ref()
+        .ok_or_else(|| error!(MarketError::MissingTokenAccount))
+}
+
+/// Checks that `stake_mint` is the mint the market was created with.
+pub fn require_stake_mint(
+    market_stake_mint: Option<Pubkey>,
+    stake_mint: &InterfaceAccount<Mint>,
+) -> Result<()> {
+    require!(
+        market_stake_mint == Some(stake_mint.key()),
+        MarketError::InvalidStakeMint
+    );
+    Ok(())
+}
+
+/// Checks that `token_account` is the canonical associated token account of `authority`.
+pub fn require_associated_token_account(
+    token_account: &InterfaceAccount<TokenAccount>,
+    authority: &Pubkey,
+    stake_mint: &InterfaceAccount<Mint>,
+    token_program: &Interface<TokenInterface>,
+) -> Result<()> {
+    let expected = get_associated_token_address_with_program_id(
+        authority,
+        &stake_mint.key(),
+        &token_program.key(),
+    );
+    require_keys_eq!(
+        token_account.key(),
+        expected,
+        MarketError::InvalidTokenAccount
+    );
+    Ok(())
+}
+
+/// Token accounts of a token-denominated market vault.
+pub struct VaultTokenAccounts<'a, 'info> {
+    pub stake_mint: &'a InterfaceAccount<'info, Mint>,
+    pub vault_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+/// Unwraps and validates the vault token accounts of a token-denominated market.
+pub fn vault_token_accounts<'a, 'info>(
+    market_stake_mint: Option<Pubkey>,
+    market_vault: &Pubkey,
+    stake_mint: &'a Option<InterfaceAccount<'info, Mint>>,
+    vault_token_account: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &'a Option<Interface<'info, TokenInterface>>,
+) -> Result<VaultTokenAccounts<'a, 'info>> {
+    let accounts = VaultTokenAccounts {
+        stake_mint: required(stake_mint)?,
+        vault_token_account: required(vault_token_account)?,
+        token_program: required(token_program)?,
+    };
+
+    require_stake_mint(market_stake_mint, accounts.stake_mint)?;
+    require_associated_token_account(
+        accounts.vault_token_account,
+        market_vault,
+        accounts.stake_mint,
+        accounts.token_program,
+    )?;
+
+    Ok(accounts)
+}
+
+pub fn transfer_tokens<'info>(
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    stake_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        from: from.to_account_info(),
+        mint: stake_mint.to_account_info(),
+        to: to.to_account_info(),
+        authority,
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    transfer_checked(cpi_ctx, amount, stake_mint.decimals)
+}
+
+/// Transfers `amount` into `to` and returns how much actually arrived, which is less than
+/// `amount` when a Token-2022 transfer fee applies.
+pub fn transfer_tokens_received<'info>(
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &mut InterfaceAccount<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    stake_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    let balance_before = to.amount;
+    transfer_tokens(from, to, authority, stake_mint, token_program, amount, &[])?;
+    to.reload()?;
+
+    to.amount
+        .checked_sub(balance_before)
+        .ok_or_else(|| error!(MarketError::MathOverflow))
+}
+
+pub fn close_token_account<'info>(
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = CloseAccount {
+        account: token_account.to_account_info(),
+        destination,
+        authority,
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    close_account(cpi_ctx)
+}
+
+/// Closes a vault token account, first moving any balance left in it to the associated token
+/// account of `sweep_owner`. Anyone can send tokens to a vault, so a stray balance must not
+/// keep it from closing.
+pub fn sweep_and_close_token_account<'info>(
+    vault: &VaultTokenAccounts<'_, 'info>,
+    sweep_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+    sweep_owner: &Pubkey,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let balance = vault.vault_token_account.amount;
+    if balance > 0 {
+        let sweep_token_account = required(sweep_token_account)?;
+        require_associated_token_account(
+            sweep_token_account,
+            sweep_owner,
+            vault.stake_mint,
+            vault.token_program,
+        )?;
+
+        transfer_tokens(
+            vault.vault_token_account,
+            sweep_token_account,
+            authority.clone(),
+            vault.stake_mint,
+            vault.token_program,
+            balance,
+            signer_seeds,
+        )?;
+    }
+
+    close_token_account(
+        vault.vault_token_account,
+        destination,
+        authority,
+        vault.token_program,
+        signer_seeds,
+    )
+}
@@ -2,11 +2,33 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { CurneyMarkets } from "../target/types/curney_markets";
 import { expect } from "chai";
+import { createHash } from "crypto";
+import { readFileSync } from "fs";
 
 const SYSTEM_PROGRAM_ID = anchor.web3.SystemProgram.programId;
 const RENT_SYSVAR_ACCOUNT = anchor.web3.SYSVAR_RENT_PUBKEY;
-const DECAY_NORMALIZATION_FACTOR = 3600;
 const FIXED_POINT_SCALE = 1e9;
+const TOKEN_PROGRAM_ID = anchor.utils.token.TOKEN_PROGRAM_ID;
+const ASSOCIATED_TOKEN_PROGRAM_ID = anchor.utils.token.ASSOCIATED_PROGRAM_ID;
+const MINT_SIZE = 82;
+// Mock Pyth price update loaded from tests/fixtures, SOL/USD at $150 published on 2100-01-01
+const MOCK_PRICE_UPDATE = new anchor.web3.PublicKey(
+	"FvyoefZ93Gnv4RjGZcjtZgWeyGiy6QWnq4uqkCEgAf5n"
+);
+const MOCK_PRICE_PUBLISH_TIME = 4102444800;
+// Admin of a platform config in the admin-keyed layout, loaded from tests/fixtures along with
+// its config and a treasury holding 2 SOL
+const LEGACY_ADMIN = anchor.web3.Keypair.fromSecretKey(
+	Uint8Array.from(
+		JSON.parse(readFileSync(`${__dirname}/fixtures/legacy-admin.json`, "utf8"))
+	)
+);
+const MOCK_PRICE_FEED_ID = Array.from(
+	Buffer.from(
+		"ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d",
+		"hex"
+	)
+);
 
 async function generateAndAirdropSigner(
 	provider: anchor.AnchorProvider
@@ -26,8 +48,7 @@ async function generateAndAirdropSigner(
 	return keypair;
 }
 
-async function calculateTotalScores(
-	resolution: number,
+async function sumPositionScores(
 	program: anchor.Program<CurneyMarkets>,
 	marketConfig: anchor.web3.PublicKey
 ): Promise<anchor.BN> {
@@ -35,37 +56,254 @@ async function calculateTotalScores(
 		(p) => p.account.market.toBase58() == marketConfig.toBase58()
 	);
 
-	if (allPositionAccounts.length === 0) return new anchor.BN(0);
-
-	let total = 0;
-	for (const pos of allPositionAccounts) {
-		const dist = Math.abs(pos.account.prediction.toNumber() - resolution);
-		const decay =
-			(DECAY_NORMALIZATION_FACTOR * pos.account.decay.toNumber()) /
-			FIXED_POINT_SCALE;
-		const exponent = -Math.pow(dist / decay, 2);
-		const score = Math.exp(exponent);
-		total += score * FIXED_POINT_SCALE;
+	return allPositionAccounts.reduce(
+		(total, pos) => total.add(pos.account.score),
+		new anchor.BN(0)
+	);
+}
+
+// Minimal SPL Token helpers, so the tests need no extra dependencies
+async function createStakeMint(
+	provider: anchor.AnchorProvider,
+	authority: anchor.web3.Keypair,
+	decimals: number
+): Promise<anchor.web3.PublicKey> {
+	const mint = anchor.web3.Keypair.generate();
+	const lamports =
+		await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+	// InitializeMint2 with no freeze authority
+	const data = Buffer.alloc(35);
+	data.writeUInt8(20, 0);
+	data.writeUInt8(decimals, 1);
+	authority.publicKey.toBuffer().copy(data, 2);
+
+	const tx = new anchor.web3.Transaction().add(
+		anchor.web3.SystemProgram.createAccount({
+			fromPubkey: authority.publicKey,
+			newAccountPubkey: mint.publicKey,
+			space: MINT_SIZE,
+			lamports,
+			programId: TOKEN_PROGRAM_ID,
+		}),
+		new anchor.web3.TransactionInstruction({
+			keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+			programId: TOKEN_PROGRAM_ID,
+			data,
+		})
+	);
+	await provider.sendAndConfirm(tx, [authority, mint]);
+	return mint.publicKey;
+}
+
+async function createTokenAccount(
+	provider: anchor.AnchorProvider,
+	payer: anchor.web3.Keypair,
+	mint: anchor.web3.PublicKey,
+	owner: anchor.web3.PublicKey
+): Promise<anchor.web3.PublicKey> {
+	const tokenAccount = anchor.utils.token.associatedAddress({ mint, owner });
+	const tx = new anchor.web3.Transaction().add(
+		new anchor.web3.TransactionInstruction({
+			keys: [
+				{ pubkey: payer.publicKey, isSigner: true, isWritable: true },
+				{ pubkey: tokenAccount, isSigner: false, isWritable: true },
+				{ pubkey: owner, isSigner: false, isWritable: false },
+				{ pubkey: mint, isSigner: false, isWritable: false },
+				{ pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+				{ pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+			],
+			programId: ASSOCIATED_TOKEN_PROGRAM_ID,
+			data: Buffer.from([1]), // CreateIdempotent
+		})
+	);
+	await provider.sendAndConfirm(tx, [payer]);
+	return tokenAccount;
+}
+
+async function mintStakeTokens(
+	provider: anchor.AnchorProvider,
+	authority: anchor.web3.Keypair,
+	mint: anchor.web3.PublicKey,
+	destination: anchor.web3.PublicKey,
+	amount: anchor.BN
+) {
+	const tx = new anchor.web3.Transaction().add(
+		new anchor.web3.TransactionInstruction({
+			keys: [
+				{ pubkey: mint, isSigner: false, isWritable: true },
+				{ pubkey: destination, isSigner: false, isWritable: true },
+				{ pubkey: authority.publicKey, isSigner: true, isWritable: false },
+			],
+			programId: TOKEN_PROGRAM_ID,
+			data: Buffer.concat([Buffer.from([7]), amount.toBuffer("le", 8)]),
+		})
+	);
+	await provider.sendAndConfirm(tx, [authority]);
+}
+
+async function tokenBalance(
+	provider: anchor.AnchorProvider,
+	tokenAccount: anchor.web3.PublicKey
+): Promise<number> {
+	const { value } = await provider.connection.getTokenAccountBalance(
+		tokenAccount
+	);
+	return Number(value.amount);
+}
+
+function findMarketAccounts(
+	programId: anchor.web3.PublicKey,
+	platformConfig: anchor.web3.PublicKey,
+	marketId: anchor.BN
+) {
+	const [marketConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("market-config"),
+			marketId.toBuffer("le", 8),
+			platformConfig.toBuffer(),
+		],
+		programId
+	);
+	const [marketState] = anchor.web3.PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("market-state"),
+			marketConfig.toBuffer(),
+			platformConfig.toBuffer(),
+		],
+		programId
+	);
+	const [marketVault] = anchor.web3.PublicKey.findProgramAddressSync(
+		[Buffer.from("market-vault"), marketConfig.toBuffer()],
+		programId
+	);
+	return { marketConfig, marketState, marketVault };
+}
+
+function findPosition(
+	programId: anchor.web3.PublicKey,
+	index: anchor.BN,
+	user: anchor.web3.PublicKey,
+	marketConfig: anchor.web3.PublicKey
+): anchor.web3.PublicKey {
+	const [position] = anchor.web3.PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("position"),
+			index.toBuffer("le", 8),
+			user.toBuffer(),
+			marketConfig.toBuffer(),
+		],
+		programId
+	);
+	return position;
+}
+
+function findMarketTombstone(
+	programId: anchor.web3.PublicKey,
+	platformConfig: anchor.web3.PublicKey,
+	marketId: anchor.BN
+) {
+	const [marketTombstone] = anchor.web3.PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("market-tombstone"),
+			marketId.toBuffer("le", 8),
+			platformConfig.toBuffer(),
+		],
+		programId
+	);
+	return marketTombstone;
+}
+
+function settlementLeaf(
+	position: anchor.web3.PublicKey,
+	reward: anchor.BN
+): Buffer {
+	return createHash("sha256")
+		.update(
+			Buffer.concat([
+				Buffer.from([0]),
+				position.toBuffer(),
+				reward.toBuffer("le", 8),
+			])
+		)
+		.digest();
+}
+
+// Prefix of the self-CPI instructions `emit_cpi!` logs events through
+const EVENT_IX_TAG = Buffer.from("e445a52e51cb9a1d", "hex");
+
+async function fetchCpiEvents(
+	provider: anchor.AnchorProvider,
+	program: Program<CurneyMarkets>,
+	signature: string
+) {
+	await provider.connection.confirmTransaction(signature, "confirmed");
+	const tx = await provider.connection.getTransaction(signature, {
+		commitment: "confirmed",
+		maxSupportedTransactionVersion: 0,
+	});
+
+	return tx.meta.innerInstructions
+		.flatMap((inner) => inner.instructions)
+		.map((ix) => Buffer.from(anchor.utils.bytes.bs58.decode(ix.data)))
+		.filter((data) => data.subarray(0, 8).equals(EVENT_IX_TAG))
+		.map((data) =>
+			program.coder.events.decode(
+				anchor.utils.bytes.base64.encode(data.subarray(8))
+			)
+		);
+}
+
+// Mirrors the integer-only scoring in `scoring.rs` so results match bit for bit
+const WAD = new anchor.BN(10).pow(new anchor.BN(18));
+const LN_2_WAD = new anchor.BN("693147180559945309");
+const MAX_EXPONENT_WAD = WAD.muln(22);
+const EXP_TAYLOR_TERMS = 18;
+
+function expNegWad(y: anchor.BN): anchor.BN {
+	if (y.gte(MAX_EXPONENT_WAD)) return new anchor.BN(0);
+
+	const k = y.div(LN_2_WAD);
+	const r = y.sub(k.mul(LN_2_WAD));
+
+	let sum = WAD.clone();
+	let term = WAD.clone();
+	for (let n = 1; n <= EXP_TAYLOR_TERMS; n++) {
+		term = term.mul(r).div(WAD.muln(n));
+		sum = n % 2 === 1 ? sum.sub(term) : sum.add(term);
 	}
 
-	return new anchor.BN(total);
+	return sum.shrn(k.toNumber());
+}
+
+function calculateScore(
+	prediction: anchor.BN,
+	resolution: anchor.BN,
+	sigma: anchor.BN,
+	stake: anchor.BN
+): anchor.BN {
+	const numerator = prediction.sub(resolution).abs();
+	const denominator = sigma;
+
+	const quotient = numerator.div(denominator);
+	if (quotient.gten(5)) return new anchor.BN(0); // Gaussian saturation distance
+
+	const x = quotient
+		.mul(WAD)
+		.add(numerator.mod(denominator).mul(WAD).div(denominator));
+	const unit = WAD.divn(FIXED_POINT_SCALE);
+	const score = expNegWad(x.mul(x).div(WAD)).add(unit.divn(2)).div(unit); // Gaussian curve
+
+	return score.mul(stake); // Linear stake weighting
 }
 
 function calculateReward(
-	prediction: number,
-	resolution: number,
-	decay: number,
-	totalPool: number,
-	totalScores: number
+	score: anchor.BN,
+	totalPool: anchor.BN,
+	totalScores: anchor.BN
 ): anchor.BN {
-	if (totalScores <= 0) return new anchor.BN(0);
-
-	const dist = Math.abs(prediction - resolution);
-	const decayFloat = (DECAY_NORMALIZATION_FACTOR * decay) / FIXED_POINT_SCALE;
-	const exponent = -Math.pow(dist / decayFloat, 2);
-	const score = Math.exp(exponent) * FIXED_POINT_SCALE;
-	const reward = (score * totalPool) / totalScores;
-	return new anchor.BN(Math.floor(reward));
+	if (totalScores.isZero()) return new anchor.BN(0);
+	return score.mul(totalPool).div(totalScores);
 }
 
 describe("curney-markets", () => {
@@ -73,29 +311,55 @@ describe("curney-markets", () => {
 	anchor.setProvider(provider);
 
 	const program = anchor.workspace.curneyMarkets as Program<CurneyMarkets>;
+	const [eventAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+		[Buffer.from("__event_authority")],
+		program.programId
+	);
 
 	let admin: anchor.web3.Keypair;
 	let creator: anchor.web3.Keypair;
 	let user: anchor.web3.Keypair;
 	let platformConfig: anchor.web3.PublicKey;
 	let platformTreasury: anchor.web3.PublicKey;
+	let resolverCommittee: anchor.web3.PublicKey;
 	let marketConfig: anchor.web3.PublicKey;
 	let marketState: anchor.web3.PublicKey;
 	let marketVault: anchor.web3.PublicKey;
 	let position: anchor.web3.PublicKey;
 
+	const platformId = new anchor.BN(
+		Math.floor(Math.random() * 1e17).toString()
+	);
 	const creatorFeeBps = 1000;
 	const platformFeeBps = 1000;
 	const marketProposalFee = new anchor.BN(
 		0.01 * anchor.web3.LAMPORTS_PER_SOL
 	);
+	const dismissalRefundBps = 5000;
+	const spamDismissalRefundBps = 0;
+	const withdrawalRefundBps = 9000;
+	const disputePeriod = new anchor.BN(0); // Resolutions are final right away unless a test opens a window
+	const disputeBond = new anchor.BN(0.05 * anchor.web3.LAMPORTS_PER_SOL);
+	const disputeRewardBps = 1000;
+	const resolverGracePeriod = new anchor.BN(3600);
+	const settlementGracePeriod = new anchor.BN(2);
+	const creatorBond = new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL);
+	const committeeTolerance = new anchor.BN(5);
 
 	const marketId = new anchor.BN(Math.floor(Math.random() * 1e17).toString());
 	const startTime = new anchor.BN(new Date().getTime() / 1000 + 1); // Added a second extra to hedge against program checks
 	const endTime = new anchor.BN(new Date().getTime() / 1000 + 7200); // 2 hours later
+	const resolutionDeadline = new anchor.BN(
+		new Date().getTime() / 1000 + 10800
+	); // 3 hours later
 	const minPredictionPrice = new anchor.BN(
 		0.01 * anchor.web3.LAMPORTS_PER_SOL
 	);
+	const sigmaMin = new anchor.BN(5);
+	const sigmaMax = new anchor.BN(50);
+	const stakeWeighting = { linear: {} };
+	const scoringCurve = { gaussian: {} };
+	const settlementMode = { onChain: {} };
 	const question =
 		"What will be the price of SOL at exactly 12:00 PM EST on January 1, 2026?";
 	const description =
@@ -106,6 +370,65 @@ describe("curney-markets", () => {
 	const secondPrediction = new anchor.BN(1);
 	const stakeAmount = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
 
+	// Proposes a market as the creator, with the parameters above unless overridden
+	function proposeMarket(
+		id: anchor.BN,
+		overrides: {
+			startTime?: anchor.BN;
+			endTime?: anchor.BN;
+			resolutionDeadline?: anchor.BN;
+			minPredictionPrice?: anchor.BN;
+			settlementMode?: Parameters<typeof program.methods.proposeMarket>[9];
+			oracle?: Parameters<typeof program.methods.proposeMarket>[10];
+			creatorBond?: anchor.BN;
+			stakeMint?: anchor.web3.PublicKey;
+		} = {}
+	) {
+		const market = findMarketAccounts(program.programId, platformConfig, id);
+		const stakeMint = overrides.stakeMint ?? null;
+		return program.methods
+			.proposeMarket(
+				id,
+				overrides.startTime ?? startTime,
+				overrides.endTime ?? endTime,
+				overrides.resolutionDeadline ?? resolutionDeadline,
+				overrides.minPredictionPrice ?? minPredictionPrice,
+				sigmaMin,
+				sigmaMax,
+				stakeWeighting,
+				scoringCurve,
+				overrides.settlementMode ?? settlementMode,
+				overrides.oracle ?? null, // Resolved manually by default
+				overrides.creatorBond ?? new anchor.BN(0), // No creator bond by default
+				question,
+				description
+			)
+			.accountsStrict({
+				creator: creator.publicKey,
+				platformConfig,
+				...market,
+				marketTombstone: findMarketTombstone(
+					program.programId,
+					platformConfig,
+					id
+				),
+				stakeMint,
+				vaultTokenAccount: stakeMint
+					? anchor.utils.token.associatedAddress({
+							mint: stakeMint,
+							owner: market.marketVault,
+					  })
+					: null,
+				tokenProgram: stakeMint ? TOKEN_PROGRAM_ID : null,
+				associatedTokenProgram: stakeMint ? ASSOCIATED_TOKEN_PROGRAM_ID : null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([creator])
+			.rpc();
+	}
+
 	before(async () => {
 		// admin = anchor.getProvider().wallet.payer;
 		admin = await generateAndAirdropSigner(provider);
@@ -113,7 +436,7 @@ describe("curney-markets", () => {
 		user = await generateAndAirdropSigner(provider);
 
 		[platformConfig] = anchor.web3.PublicKey.findProgramAddressSync(
-			[Buffer.from("platform-config"), admin.publicKey.toBuffer()],
+			[Buffer.from("platform-config"), platformId.toBuffer("le", 8)],
 			program.programId
 		);
 
@@ -122,6 +445,11 @@ describe("curney-markets", () => {
 			program.programId
 		);
 
+		[resolverCommittee] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("resolver-committee"), platformConfig.toBuffer()],
+			program.programId
+		);
+
 		[marketConfig] = anchor.web3.PublicKey.findProgramAddressSync(
 			[
 				Buffer.from("market-config"),
@@ -149,53 +477,111 @@ describe("curney-markets", () => {
 	it("should initialize platform config", async () => {
 		await program.methods
 			.initializePlatform(
+				platformId,
 				creatorFeeBps,
 				platformFeeBps,
-				marketProposalFee
+				marketProposalFee,
+				dismissalRefundBps,
+				spamDismissalRefundBps,
+				withdrawalRefundBps,
+				disputePeriod,
+				disputeBond,
+				disputeRewardBps,
+				resolverGracePeriod,
+				settlementGracePeriod
 			)
 			.accountsStrict({
 				admin: admin.publicKey,
 				platformConfig,
 				platformTreasury,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([admin])
 			.rpc();
 
 		const platformConfigAccount =
 			await program.account.platformConfig.fetch(platformConfig);
+		expect(platformConfigAccount.platformId.toString()).to.equal(
+			platformId.toString()
+		);
+		expect(platformConfigAccount.namespace.toBase58()).to.equal(
+			platformConfig.toBase58()
+		);
+		expect(platformConfigAccount.pendingAdmin).to.be.null;
 		expect(platformConfigAccount.creatorFeeBps).to.equal(creatorFeeBps);
 		expect(platformConfigAccount.platformFeeBps).to.equal(platformFeeBps);
 		expect(platformConfigAccount.marketProposalFee.toNumber()).to.equal(
 			marketProposalFee.toNumber()
 		);
+		expect(platformConfigAccount.dismissalRefundBps).to.equal(
+			dismissalRefundBps
+		);
+		expect(platformConfigAccount.spamDismissalRefundBps).to.equal(
+			spamDismissalRefundBps
+		);
+		expect(platformConfigAccount.withdrawalRefundBps).to.equal(
+			withdrawalRefundBps
+		);
+		expect(platformConfigAccount.disputePeriod.toNumber()).to.equal(
+			disputePeriod.toNumber()
+		);
+		expect(platformConfigAccount.disputeBond.toNumber()).to.equal(
+			disputeBond.toNumber()
+		);
+		expect(platformConfigAccount.disputeRewardBps).to.equal(disputeRewardBps);
+		expect(platformConfigAccount.resolverGracePeriod.toNumber()).to.equal(
+			resolverGracePeriod.toNumber()
+		);
+		expect(platformConfigAccount.settlementGracePeriod.toNumber()).to.equal(
+			settlementGracePeriod.toNumber()
+		);
 		expect(platformConfigAccount.admin.toBase58()).equals(
 			admin.publicKey.toBase58()
 		);
+		// Every role starts with the admin
+		for (const holder of [
+			platformConfigAccount.approver,
+			platformConfigAccount.resolver,
+			platformConfigAccount.treasurer,
+			platformConfigAccount.pauser,
+			platformConfigAccount.arbiter,
+		]) {
+			expect(holder.toBase58()).equals(admin.publicKey.toBase58());
+		}
+		expect(platformConfigAccount.isPaused).to.be.false;
 	});
 
-	it("should propose a market", async () => {
+	it("should initialize the resolver committee", async () => {
 		await program.methods
-			.proposeMarket(
-				marketId,
-				startTime,
-				endTime,
-				minPredictionPrice,
-				question,
-				description
-			)
+			.initializeResolverCommittee([admin.publicKey], 1, committeeTolerance)
 			.accountsStrict({
-				creator: creator.publicKey,
+				admin: admin.publicKey,
 				platformConfig,
-				platformTreasury,
-				marketConfig,
-				marketState,
-				marketVault,
+				resolverCommittee,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
-			.signers([creator])
+			.signers([admin])
 			.rpc();
 
+		const committeeAccount = await program.account.resolverCommittee.fetch(
+			resolverCommittee
+		);
+		expect(committeeAccount.threshold).to.equal(1);
+		expect(committeeAccount.tolerance.toNumber()).to.equal(
+			committeeTolerance.toNumber()
+		);
+		expect(committeeAccount.members.map((m) => m.toBase58())).to.deep.equal([
+			admin.publicKey.toBase58(),
+		]);
+	});
+
+	it("should propose a market", async () => {
+		await proposeMarket(marketId);
+
 		const marketConfigAccount = await program.account.marketConfig.fetch(
 			marketConfig
 		);
@@ -213,6 +599,23 @@ describe("curney-markets", () => {
 		expect(marketConfigAccount.endTime.toNumber()).to.equal(
 			endTime.toNumber()
 		);
+		expect(marketConfigAccount.sigmaMin.toNumber()).to.equal(
+			sigmaMin.toNumber()
+		);
+		expect(marketConfigAccount.sigmaMax.toNumber()).to.equal(
+			sigmaMax.toNumber()
+		);
+
+		// The proposal fee is escrowed in the market vault until approval
+		expect(marketConfigAccount.proposalFee.toNumber()).to.equal(
+			marketProposalFee.toNumber()
+		);
+		expect(await provider.connection.getBalance(marketVault)).to.equal(
+			marketProposalFee.toNumber()
+		);
+		expect(marketConfigAccount.stakeWeighting).to.deep.equal(stakeWeighting);
+		expect(marketConfigAccount.scoringCurve).to.deep.equal(scoringCurve);
+		expect(marketConfigAccount.settlementMode).to.deep.equal(settlementMode);
 		expect(marketConfigAccount.creator.toBase58()).equals(
 			creator.publicKey.toBase58()
 		);
@@ -223,10 +626,9 @@ describe("curney-markets", () => {
 		const marketStateAccount = await program.account.marketState.fetch(
 			marketState
 		);
-		expect(marketStateAccount.isApproved).to.be.false;
-		expect(marketStateAccount.isResolved).to.be.false;
+		expect(marketStateAccount.status).to.deep.equal({ proposed: {} });
 		expect(marketStateAccount.resolution).to.be.null;
-		expect(marketStateAccount.totalScores).to.be.null;
+		expect(marketStateAccount.totalScores.toNumber()).to.equal(0);
 		expect(marketStateAccount.totalPool.toNumber()).to.equal(0);
 		expect(marketStateAccount.totalPositions.toNumber()).to.equal(0);
 		expect(marketStateAccount.creatorFeeRevenue.toNumber()).to.equal(0);
@@ -246,16 +648,24 @@ describe("curney-markets", () => {
 			.updateMarketConfig(
 				null, // Not updating the start time
 				newEndTime,
+				null, // Not updating the resolution deadline
 				null, // Not updating the min prediction price
+				null, // Not updating the sigma min
+				null, // Not updating the sigma max
+				null, // Not updating the stake weighting
+				null, // Not updating the scoring curve
+				null, // Not updating the settlement mode
 				newQuestion,
 				newDescription
 			)
 			.accountsStrict({
-				admin: admin.publicKey,
+				authority: admin.publicKey,
 				marketConfig,
 				marketState,
 				platformConfig,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([admin])
 			.rpc();
@@ -263,6 +673,7 @@ describe("curney-markets", () => {
 		const marketConfigAccount = await program.account.marketConfig.fetch(
 			marketConfig
 		);
+		expect(marketConfigAccount.revision).to.equal(1);
 		expect(marketConfigAccount.question).to.equal(newQuestion);
 		expect(marketConfigAccount.description).to.equal(newDescription);
 		expect(marketConfigAccount.endTime.toNumber()).to.equal(
@@ -277,14 +688,22 @@ describe("curney-markets", () => {
 	});
 
 	it("should approve a market", async () => {
+		const treasuryBalanceBefore = await provider.connection.getBalance(
+			platformTreasury
+		);
+
 		await program.methods
-			.approveMarket()
+			.approveMarket(null, 1) // The revision left by the admin update
 			.accountsStrict({
-				admin: admin.publicKey,
+				approver: admin.publicKey,
 				marketConfig,
 				marketState,
+				marketVault,
 				platformConfig,
+				platformTreasury,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([admin])
 			.rpc();
@@ -292,7 +711,69 @@ describe("curney-markets", () => {
 		const marketStateAccount = await program.account.marketState.fetch(
 			marketState
 		);
-		expect(marketStateAccount.isApproved).to.be.true;
+		expect(marketStateAccount.status).to.deep.equal({ approved: {} });
+
+		// The escrowed proposal fee moves to the treasury
+		expect(await provider.connection.getBalance(platformTreasury)).to.equal(
+			treasuryBalanceBefore + marketProposalFee.toNumber()
+		);
+		expect(await provider.connection.getBalance(marketVault)).to.equal(0);
+	});
+
+	it("should not approve a market twice", async () => {
+		let transitionError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.approveMarket(null, 1)
+				.accountsStrict({
+					approver: admin.publicKey,
+					marketConfig,
+					marketState,
+					marketVault,
+					platformConfig,
+					platformTreasury,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			transitionError = err;
+		}
+		expect(transitionError?.error.errorCode.code).to.equal(
+			"InvalidStatusTransition"
+		);
+	});
+
+	it("should not withdraw an approved proposal", async () => {
+		let withdrawError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.withdrawProposal()
+				.accountsStrict({
+					creator: creator.publicKey,
+					platformConfig,
+					platformTreasury,
+					marketConfig,
+					marketState,
+					marketVault,
+					stakeMint: null,
+					vaultTokenAccount: null,
+					creatorTokenAccount: null,
+					tokenProgram: null,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([creator])
+				.rpc();
+		} catch (err) {
+			withdrawError = err;
+		}
+		expect(withdrawError?.error.errorCode.code).to.equal(
+			"InvalidStatusTransition"
+		);
 	});
 
 	it("should place a prediction", async () => {
@@ -309,7 +790,7 @@ describe("curney-markets", () => {
 			program.programId
 		);
 
-		await program.methods
+		const signature = await program.methods
 			.placePrediction(prediction, stakeAmount)
 			.accountsStrict({
 				user: user.publicKey,
@@ -318,8 +799,13 @@ describe("curney-markets", () => {
 				marketState,
 				marketVault,
 				platformConfig,
-				platformTreasury,
+				stakeMint: null,
+				userTokenAccount: null,
+				vaultTokenAccount: null,
+				tokenProgram: null,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([user])
 			.rpc();
@@ -329,6 +815,14 @@ describe("curney-markets", () => {
 		const actualStakeAmount =
 			stakeAmount.toNumber() - platformFee - creatorRevenue;
 
+		const [event] = await fetchCpiEvents(provider, program, signature);
+		expect(event.name).to.equal("predictionPlaced");
+		expect(event.data.grossStake.toNumber()).to.equal(stakeAmount.toNumber());
+		expect(event.data.platformFee.toNumber()).to.equal(platformFee);
+		expect(event.data.creatorFee.toNumber()).to.equal(creatorRevenue);
+		expect(event.data.netStake.toNumber()).to.equal(actualStakeAmount);
+		expect(event.data.position.toBase58()).to.equal(position.toBase58());
+
 		const positionAccount = await program.account.position.fetch(position);
 		expect(positionAccount.reward).to.be.null;
 		expect(positionAccount.claimed).to.be.false;
@@ -336,6 +830,10 @@ describe("curney-markets", () => {
 		expect(positionAccount.index.toNumber()).to.equal(
 			currentIndex.toNumber()
 		);
+		expect(positionAccount.sigma.toNumber()).to.be.within(
+			sigmaMin.toNumber(),
+			sigmaMax.toNumber()
+		);
 		expect(positionAccount.prediction.toNumber()).to.equal(
 			prediction.toNumber()
 		);
@@ -379,8 +877,13 @@ describe("curney-markets", () => {
 				marketState,
 				marketVault,
 				platformConfig,
-				platformTreasury,
+				stakeMint: null,
+				userTokenAccount: null,
+				vaultTokenAccount: null,
+				tokenProgram: null,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([user])
 			.rpc();
@@ -397,6 +900,10 @@ describe("curney-markets", () => {
 		expect(positionAccount.index.toNumber()).to.equal(
 			currentIndex.toNumber()
 		);
+		expect(positionAccount.sigma.toNumber()).to.be.within(
+			sigmaMin.toNumber(),
+			sigmaMax.toNumber()
+		);
 		expect(positionAccount.prediction.toNumber()).to.equal(
 			secondPrediction.toNumber()
 		);
@@ -420,20 +927,18 @@ describe("curney-markets", () => {
 
 	it("should resolve a market", async () => {
 		await new Promise((resolve) => setTimeout(resolve, 2000)); // Wait the market to end
-		const totalScores = await calculateTotalScores(
-			resolution.toNumber(),
-			program,
-			marketConfig
-		);
 
 		await program.methods
-			.resolveMarket(resolution, totalScores)
+			.resolveMarket(resolution)
 			.accountsStrict({
-				admin: admin.publicKey,
+				resolver: admin.publicKey,
+				resolverCommittee,
 				marketConfig,
 				marketState,
 				platformConfig,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([admin])
 			.rpc();
@@ -441,14 +946,82 @@ describe("curney-markets", () => {
 		const marketStateAccount = await program.account.marketState.fetch(
 			marketState
 		);
-		expect(marketStateAccount.isResolved).to.be.true;
+		expect(marketStateAccount.status).to.deep.equal({ resolved: {} });
 		expect(marketStateAccount.resolution).to.not.be.null;
-		expect(marketStateAccount.totalScores.toNumber()).equals(
-			totalScores.toNumber()
-		);
 		expect(marketStateAccount.resolution.toNumber()).equals(
 			resolution.toNumber()
 		);
+		expect(marketStateAccount.scoredPositions.toNumber()).to.equal(0);
+	});
+
+	it("should amend a resolution before claims begin", async () => {
+		const amendResolution = (value: anchor.BN) =>
+			program.methods
+				.amendResolution(value)
+				.accountsStrict({
+					resolver: admin.publicKey,
+					marketConfig,
+					marketState,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+
+		// Correct a mistyped resolution, then restore it for the claims below
+		await amendResolution(resolution.addn(1));
+		let marketStateAccount = await program.account.marketState.fetch(
+			marketState
+		);
+		expect(marketStateAccount.resolution.toNumber()).to.equal(
+			resolution.toNumber() + 1
+		);
+		expect(marketStateAccount.resolutionAmendments).to.equal(1);
+
+		await amendResolution(resolution);
+		marketStateAccount = await program.account.marketState.fetch(marketState);
+		expect(marketStateAccount.resolution.toNumber()).to.equal(
+			resolution.toNumber()
+		);
+		expect(marketStateAccount.resolutionAmendments).to.equal(2);
+	});
+
+	it("should settle every position", async () => {
+		const positionAccounts = (await program.account.position.all()).filter(
+			(p) => p.account.market.toBase58() == marketConfig.toBase58()
+		);
+
+		await program.methods
+			.settlePositions()
+			.accountsStrict({
+				marketConfig,
+				marketState,
+				platformConfig,
+				eventAuthority,
+				program: program.programId,
+			})
+			.remainingAccounts(
+				positionAccounts.map((p) => ({
+					pubkey: p.publicKey,
+					isSigner: false,
+					isWritable: true,
+				}))
+			)
+			.rpc();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			marketState
+		);
+		const totalScores = await sumPositionScores(program, marketConfig);
+		expect(marketStateAccount.scoredPositions.toNumber()).to.equal(
+			marketStateAccount.totalPositions.toNumber()
+		);
+		expect(marketStateAccount.totalScores.toString()).to.equal(
+			totalScores.toString()
+		);
+		expect(marketStateAccount.status).to.deep.equal({ settled: {} });
 	});
 
 	it("should claim a position reward", async () => {
@@ -471,7 +1044,13 @@ describe("curney-markets", () => {
 				marketVault,
 				platformConfig,
 				position,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				userTokenAccount: null,
+				tokenProgram: null,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([user])
 			.rpc();
@@ -483,17 +1062,18 @@ describe("curney-markets", () => {
 		const marketStateAccount = await program.account.marketState.fetch(
 			marketState
 		);
-		const totalScores = await calculateTotalScores(
-			resolution.toNumber(),
-			program,
-			marketConfig
+		const totalScores = await sumPositionScores(program, marketConfig);
+		const score = calculateScore(
+			positionAccount.prediction,
+			marketStateAccount.resolution,
+			positionAccount.sigma,
+			positionAccount.stake
 		);
+		expect(positionAccount.score.toString()).to.equal(score.toString());
 		const reward = calculateReward(
-			positionAccount.prediction.toNumber(),
-			marketStateAccount.resolution.toNumber(),
-			positionAccount.decay.toNumber(),
-			marketStateAccount.totalPool.toNumber(),
-			totalScores.toNumber()
+			positionAccount.score,
+			marketStateAccount.totalPool,
+			totalScores
 		);
 		expect(positionAccount.reward.toNumber()).to.equal(reward.toNumber());
 	});
@@ -518,7 +1098,13 @@ describe("curney-markets", () => {
 				marketVault,
 				platformConfig,
 				position,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				userTokenAccount: null,
+				tokenProgram: null,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([user])
 			.rpc();
@@ -530,151 +1116,2367 @@ describe("curney-markets", () => {
 		const marketStateAccount = await program.account.marketState.fetch(
 			marketState
 		);
-		const totalScores = await calculateTotalScores(
-			resolution.toNumber(),
-			program,
-			marketConfig
+		const totalScores = await sumPositionScores(program, marketConfig);
+		const score = calculateScore(
+			positionAccount.prediction,
+			marketStateAccount.resolution,
+			positionAccount.sigma,
+			positionAccount.stake
 		);
+		expect(positionAccount.score.toString()).to.equal(score.toString());
 		const reward = calculateReward(
-			positionAccount.prediction.toNumber(),
-			marketStateAccount.resolution.toNumber(),
-			positionAccount.decay.toNumber(),
-			marketStateAccount.totalPool.toNumber(),
-			totalScores.toNumber()
+			positionAccount.score,
+			marketStateAccount.totalPool,
+			totalScores
 		);
 		expect(positionAccount.reward.toNumber()).to.equal(reward.toNumber());
 	});
 
-	it("should dismiss a market", async () => {
-		const newMarketId = new anchor.BN(
-			Math.floor(Math.random() * 1e17).toString()
+	it("should not amend a resolution once claims have started", async () => {
+		let amendError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.amendResolution(resolution.addn(1))
+				.accountsStrict({
+					resolver: admin.publicKey,
+					marketConfig,
+					marketState,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			amendError = err;
+		}
+		expect(amendError?.error.errorCode.code).to.equal(
+			"CorrectionWindowClosed"
 		);
+	});
 
-		const newStartTime = new anchor.BN(new Date().getTime() / 1000 + 1);
-
-		const [newMarketConfig] = anchor.web3.PublicKey.findProgramAddressSync(
-			[
-				Buffer.from("market-config"),
-				newMarketId.toBuffer("le", 8),
-				platformConfig.toBuffer(),
-			],
-			program.programId
+	it("should settle and claim a market by merkle root", async () => {
+		const merkleMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
 		);
-
-		const [newMarketState] = anchor.web3.PublicKey.findProgramAddressSync(
-			[
-				Buffer.from("market-state"),
-				newMarketConfig.toBuffer(),
-				platformConfig.toBuffer(),
-			],
-			program.programId
+		const merkleMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			merkleMarketId
 		);
+		const now = new Date().getTime() / 1000;
 
-		const [newMarketVault] = anchor.web3.PublicKey.findProgramAddressSync(
-			[Buffer.from("market-vault"), newMarketConfig.toBuffer()],
-			program.programId
-		);
+		await proposeMarket(merkleMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 3),
+			settlementMode: { merkleRoot: {} },
+		});
 
 		await program.methods
-			.proposeMarket(
-				newMarketId,
-				newStartTime,
-				endTime,
-				minPredictionPrice,
-				question,
-				description
-			)
+			.approveMarket(null, 0)
 			.accountsStrict({
-				creator: creator.publicKey,
+				approver: admin.publicKey,
+				marketConfig: merkleMarket.marketConfig,
+				marketState: merkleMarket.marketState,
+				marketVault: merkleMarket.marketVault,
 				platformConfig,
 				platformTreasury,
-				marketConfig: newMarketConfig,
-				marketState: newMarketState,
-				marketVault: newMarketVault,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
-			.signers([creator])
+			.signers([admin])
 			.rpc();
 
+		await new Promise((resolve) => setTimeout(resolve, 1500)); // Wait the market to start
+		const merklePosition = findPosition(
+			program.programId,
+			new anchor.BN(0),
+			user.publicKey,
+			merkleMarket.marketConfig
+		);
+
 		await program.methods
-			.dismissMarket()
+			.placePrediction(prediction, stakeAmount)
 			.accountsStrict({
-				admin: admin.publicKey,
-				creator: creator.publicKey,
-				marketConfig: newMarketConfig,
-				marketState: newMarketState,
-				marketVault: newMarketVault,
+				user: user.publicKey,
+				position: merklePosition,
 				platformConfig,
-				platformTreasury,
+				...merkleMarket,
+				stakeMint: null,
+				userTokenAccount: null,
+				vaultTokenAccount: null,
+				tokenProgram: null,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
-			.signers([admin])
+			.signers([user])
 			.rpc();
 
-		try {
-			await program.account.marketConfig.fetch(marketConfig);
-		} catch (error) {
-			expect(error.toString()).to.include("Account does not exist");
-		}
+		await new Promise((resolve) => setTimeout(resolve, 2000)); // Wait the market to end
+		await program.methods
+			.resolveMarket(resolution)
+			.accountsStrict({
+				resolver: admin.publicKey,
+				resolverCommittee,
+				marketConfig: merkleMarket.marketConfig,
+				marketState: merkleMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
 
-		try {
-			await program.account.marketState.fetch(marketState);
-		} catch (error) {
-			expect(error.toString()).to.include("Account does not exist");
-		}
-	});
+		// A single-leaf tree has the leaf itself as root and an empty proof
+		const { totalPool } = await program.account.marketState.fetch(
+			merkleMarket.marketState
+		);
+		const root = settlementLeaf(merklePosition, totalPool);
 
-	it("should withdraw creator revenue", async () => {
 		await program.methods
-			.withdrawCreatorRevenue()
+			.postSettlementRoot(Array.from(root), totalPool)
 			.accountsStrict({
-				creator: creator.publicKey,
-				marketConfig,
-				marketState,
-				marketVault,
+				resolver: admin.publicKey,
+				marketConfig: merkleMarket.marketConfig,
+				marketState: merkleMarket.marketState,
 				platformConfig,
-				rent: RENT_SYSVAR_ACCOUNT,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
-			.signers([creator])
+			.signers([admin])
 			.rpc();
-	});
 
-	it("should withdraw platform fees", async () => {
 		await program.methods
-			.withdrawPlatformFees()
+			.claimSettledReward(totalPool, [])
 			.accountsStrict({
-				admin: admin.publicKey,
+				user: user.publicKey,
+				position: merklePosition,
+				marketConfig: merkleMarket.marketConfig,
+				marketState: merkleMarket.marketState,
+				marketVault: merkleMarket.marketVault,
 				platformConfig,
-				platformTreasury,
-				rent: RENT_SYSVAR_ACCOUNT,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				userTokenAccount: null,
+				tokenProgram: null,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
-			.signers([admin])
+			.signers([user])
 			.rpc();
+
+		const positionAccount = await program.account.position.fetch(
+			merklePosition
+		);
+		expect(positionAccount.claimed).to.be.true;
+		expect(positionAccount.reward.toString()).to.equal(totalPool.toString());
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			merkleMarket.marketState
+		);
+		expect(marketStateAccount.claimedPayout.toString()).to.equal(
+			totalPool.toString()
+		);
 	});
 
-	it("should update platform config", async () => {
-		const newCreatorFeeBps = 100;
-		const newMarketProposalFee = new anchor.BN(
-			0.001 * anchor.web3.LAMPORTS_PER_SOL
+	it("should void a market and refund the gross stake", async () => {
+		const voidMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
 		);
+		const voidedMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			voidMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(voidMarketId, {
+			startTime: new anchor.BN(now + 1),
+		});
+
 		await program.methods
-			.updatePlatformConfig(newCreatorFeeBps, null, newMarketProposalFee)
+			.approveMarket(null, 0)
 			.accountsStrict({
-				admin: admin.publicKey,
+				approver: admin.publicKey,
+				marketConfig: voidedMarket.marketConfig,
+				marketState: voidedMarket.marketState,
+				marketVault: voidedMarket.marketVault,
 				platformConfig,
+				platformTreasury,
 				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
 			})
 			.signers([admin])
 			.rpc();
 
-		const platformConfigAccount =
+		await new Promise((resolve) => setTimeout(resolve, 1500)); // Wait the market to start
+		const voidedPosition = findPosition(
+			program.programId,
+			new anchor.BN(0),
+			user.publicKey,
+			voidedMarket.marketConfig
+		);
+
+		await program.methods
+			.placePrediction(prediction, stakeAmount)
+			.accountsStrict({
+				user: user.publicKey,
+				position: voidedPosition,
+				platformConfig,
+				...voidedMarket,
+				stakeMint: null,
+				userTokenAccount: null,
+				vaultTokenAccount: null,
+				tokenProgram: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		await program.methods
+			.voidMarket({ grossStake: {} })
+			.accountsStrict({
+				admin: admin.publicKey,
+				marketConfig: voidedMarket.marketConfig,
+				marketState: voidedMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			voidedMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ voided: {} });
+		expect(marketStateAccount.refundPolicy).to.deep.equal({ grossStake: {} });
+		expect(marketStateAccount.creatorFeeRevenue.toNumber()).to.equal(0);
+
+		const userBalanceBefore = await provider.connection.getBalance(
+			user.publicKey
+		);
+
+		await program.methods
+			.refundPosition()
+			.accountsStrict({
+				user: user.publicKey,
+				position: voidedPosition,
+				platformConfig,
+				...voidedMarket,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				userTokenAccount: null,
+				tokenProgram: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const userBalanceAfter = await provider.connection.getBalance(
+			user.publicKey
+		);
+		expect(userBalanceAfter - userBalanceBefore).to.equal(
+			stakeAmount.toNumber()
+		);
+
+		const positionAccount = await program.account.position.fetch(
+			voidedPosition
+		);
+		expect(positionAccount.claimed).to.be.true;
+	});
+
+	it("should expire an unresolved market and refund its stakes", async () => {
+		const expiredMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const expiredMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			expiredMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(expiredMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 3),
+			resolutionDeadline: new anchor.BN(now + 4),
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: expiredMarket.marketConfig,
+				marketState: expiredMarket.marketState,
+				marketVault: expiredMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 1500)); // Wait the market to start
+		const expiredPosition = findPosition(
+			program.programId,
+			new anchor.BN(0),
+			user.publicKey,
+			expiredMarket.marketConfig
+		);
+
+		await program.methods
+			.placePrediction(prediction, stakeAmount)
+			.accountsStrict({
+				user: user.publicKey,
+				position: expiredPosition,
+				platformConfig,
+				...expiredMarket,
+				stakeMint: null,
+				userTokenAccount: null,
+				vaultTokenAccount: null,
+				tokenProgram: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 3000)); // Wait the resolution deadline
+		await program.methods
+			.expireMarket()
+			.accountsStrict({
+				signer: user.publicKey,
+				marketConfig: expiredMarket.marketConfig,
+				marketState: expiredMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			expiredMarket.marketState
+		);
+		expect(marketStateAccount.isExpired).to.be.true;
+		expect(marketStateAccount.status).to.deep.equal({ voided: {} });
+
+		const userBalanceBefore = await provider.connection.getBalance(
+			user.publicKey
+		);
+
+		await program.methods
+			.refundPosition()
+			.accountsStrict({
+				user: user.publicKey,
+				position: expiredPosition,
+				platformConfig,
+				...expiredMarket,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				userTokenAccount: null,
+				tokenProgram: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		// The platform fee stays escrowed for the platform
+		const platformFee = (stakeAmount.toNumber() * platformFeeBps) / 10000;
+		const userBalanceAfter = await provider.connection.getBalance(
+			user.publicKey
+		);
+		expect(userBalanceAfter - userBalanceBefore).to.equal(
+			stakeAmount.toNumber() - platformFee
+		);
+	});
+
+	it("should expire a resolved market whose settlement root is never posted", async () => {
+		const stalledMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const stalledMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			stalledMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(stalledMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 2),
+			resolutionDeadline: new anchor.BN(now + 4),
+			settlementMode: { merkleRoot: {} },
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: stalledMarket.marketConfig,
+				marketState: stalledMarket.marketState,
+				marketVault: stalledMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the market to end
+		await program.methods
+			.resolveMarket(resolution)
+			.accountsStrict({
+				resolver: admin.publicKey,
+				resolverCommittee,
+				marketConfig: stalledMarket.marketConfig,
+				marketState: stalledMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const expireStalledMarket = () =>
+			program.methods
+				.expireMarket()
+				.accountsStrict({
+					signer: user.publicKey,
+					marketConfig: stalledMarket.marketConfig,
+					marketState: stalledMarket.marketState,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([user])
+				.rpc();
+
+		let deadlineError: anchor.AnchorError | undefined;
+		try {
+			await expireStalledMarket();
+		} catch (err) {
+			deadlineError = err;
+		}
+		expect(deadlineError?.error.errorCode.code).to.equal(
+			"SettlementGracePeriod"
+		);
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the settlement grace period
+		await expireStalledMarket();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			stalledMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ voided: {} });
+		expect(marketStateAccount.isExpired).to.be.true;
+		expect(marketStateAccount.refundPolicy).to.deep.equal({ stakeOnly: {} });
+	});
+
+	it("should expire a disputed market the arbiter never rules on", async () => {
+		const setDisputePeriod = (period: anchor.BN) =>
+			program.methods
+				.updatePlatformConfig(
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					period,
+					null,
+					null,
+					null,
+					null
+				)
+				.accountsStrict({
+					authority: admin.publicKey,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+
+		await setDisputePeriod(new anchor.BN(2));
+
+		const stalledMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const stalledMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			stalledMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(stalledMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 2),
+			resolutionDeadline: new anchor.BN(now + 4),
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: stalledMarket.marketConfig,
+				marketState: stalledMarket.marketState,
+				marketVault: stalledMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the market to end
+		await program.methods
+			.resolveMarket(resolution)
+			.accountsStrict({
+				resolver: admin.publicKey,
+				resolverCommittee,
+				marketConfig: stalledMarket.marketConfig,
+				marketState: stalledMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const [dispute] = anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("dispute"),
+				stalledMarket.marketConfig.toBuffer(),
+				user.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+
+		await program.methods
+			.disputeResolution(new anchor.BN(145))
+			.accountsStrict({
+				challenger: user.publicKey,
+				marketConfig: stalledMarket.marketConfig,
+				marketState: stalledMarket.marketState,
+				platformConfig,
+				dispute,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const expireStalledMarket = () =>
+			program.methods
+				.expireMarket()
+				.accountsStrict({
+					signer: user.publicKey,
+					marketConfig: stalledMarket.marketConfig,
+					marketState: stalledMarket.marketState,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([user])
+				.rpc();
+
+		// The settlement grace period past the dispute window still gives the arbiter time to rule
+		await new Promise((resolve) => setTimeout(resolve, 2000)); // Wait the dispute window
+		let deadlineError: anchor.AnchorError | undefined;
+		try {
+			await expireStalledMarket();
+		} catch (err) {
+			deadlineError = err;
+		}
+		expect(deadlineError?.error.errorCode.code).to.equal(
+			"SettlementGracePeriod"
+		);
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the settlement grace period
+		await expireStalledMarket();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			stalledMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ voided: {} });
+		expect(marketStateAccount.refundPolicy).to.deep.equal({ stakeOnly: {} });
+
+		// The challenger gets the bond and rent back
+		const userBalanceBefore = await provider.connection.getBalance(
+			user.publicKey
+		);
+		const disputeBalance = await provider.connection.getBalance(dispute);
+
+		const signature = await program.methods
+			.returnDisputeBond()
+			.accountsStrict({
+				signer: admin.publicKey,
+				challenger: user.publicKey,
+				platformConfig,
+				marketConfig: stalledMarket.marketConfig,
+				marketState: stalledMarket.marketState,
+				dispute,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const [event] = await fetchCpiEvents(provider, program, signature);
+		expect(event.name).to.equal("disputeBondReturned");
+		expect(event.data.bond.toNumber()).to.equal(disputeBond.toNumber());
+
+		const userBalanceAfter = await provider.connection.getBalance(
+			user.publicKey
+		);
+		expect(userBalanceAfter - userBalanceBefore).to.equal(disputeBalance);
+
+		await setDisputePeriod(disputePeriod);
+	});
+
+	it("should overturn a disputed resolution", async () => {
+		const setDisputePeriod = (period: anchor.BN) =>
+			program.methods
+				.updatePlatformConfig(
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					period,
+					null,
+					null,
+					null,
+					null
+				)
+				.accountsStrict({
+					authority: admin.publicKey,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+
+		await setDisputePeriod(new anchor.BN(60));
+
+		const disputedMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const disputedMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			disputedMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(disputedMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 2),
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: disputedMarket.marketConfig,
+				marketState: disputedMarket.marketState,
+				marketVault: disputedMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the market to end
+		await program.methods
+			.resolveMarket(resolution)
+			.accountsStrict({
+				resolver: admin.publicKey,
+				resolverCommittee,
+				marketConfig: disputedMarket.marketConfig,
+				marketState: disputedMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const [dispute] = anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("dispute"),
+				disputedMarket.marketConfig.toBuffer(),
+				user.publicKey.toBuffer(),
+			],
+			program.programId
+		);
+		const proposedResolution = new anchor.BN(145);
+
+		await program.methods
+			.disputeResolution(proposedResolution)
+			.accountsStrict({
+				challenger: user.publicKey,
+				marketConfig: disputedMarket.marketConfig,
+				marketState: disputedMarket.marketState,
+				platformConfig,
+				dispute,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		let marketStateAccount = await program.account.marketState.fetch(
+			disputedMarket.marketState
+		);
+		expect(marketStateAccount.openDisputes.toNumber()).to.equal(1);
+
+		const userBalanceBefore = await provider.connection.getBalance(
+			user.publicKey
+		);
+		const disputeRent = await provider.connection.getBalance(dispute);
+
+		await program.methods
+			.settleDispute(true)
+			.accountsStrict({
+				arbiter: admin.publicKey,
+				challenger: user.publicKey,
+				platformConfig,
+				platformTreasury,
+				marketConfig: disputedMarket.marketConfig,
+				marketState: disputedMarket.marketState,
+				dispute,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		marketStateAccount = await program.account.marketState.fetch(
+			disputedMarket.marketState
+		);
+		expect(marketStateAccount.openDisputes.toNumber()).to.equal(0);
+		expect(marketStateAccount.resolution.toNumber()).to.equal(
+			proposedResolution.toNumber()
+		);
+
+		// The bond and rent come back along with the dispute reward
+		const userBalanceAfter = await provider.connection.getBalance(
+			user.publicKey
+		);
+		expect(userBalanceAfter - userBalanceBefore).to.equal(
+			disputeRent + (disputeBond.toNumber() * disputeRewardBps) / 10000
+		);
+
+		await setDisputePeriod(disputePeriod);
+	});
+
+	it("should resolve a market from a price feed", async () => {
+		const oracleMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const oracleMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			oracleMarketId
+		);
+		const now = Math.floor(new Date().getTime() / 1000);
+		const oracleEndTime = new anchor.BN(now + 2);
+
+		// The mock price is published far in the future, so the window must reach it
+		const oracle = {
+			feedId: MOCK_PRICE_FEED_ID,
+			exponent: 0,
+			maxStaleness: new anchor.BN(MOCK_PRICE_PUBLISH_TIME - now),
+			sampling: null,
+		};
+
+		await proposeMarket(oracleMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: oracleEndTime,
+			oracle,
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: oracleMarket.marketConfig,
+				marketState: oracleMarket.marketState,
+				marketVault: oracleMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 3000)); // Wait the market to end
+		await program.methods
+			.resolveFromOracle()
+			.accountsStrict({
+				signer: user.publicKey,
+				platformConfig,
+				marketConfig: oracleMarket.marketConfig,
+				marketState: oracleMarket.marketState,
+				priceUpdate: MOCK_PRICE_UPDATE,
+				priceSamples: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			oracleMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ resolved: {} });
+		expect(marketStateAccount.resolution.toNumber()).to.equal(150);
+	});
+
+	it("should sample a price feed at a limited rate", async () => {
+		const sampledMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const sampledMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			sampledMarketId
+		);
+		const [priceSamples] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("price-samples"), sampledMarket.marketConfig.toBuffer()],
+			program.programId
+		);
+		const now = Math.floor(new Date().getTime() / 1000);
+
+		// The mock price is published far in the future, so the window must close at it
+		const oracle = {
+			feedId: MOCK_PRICE_FEED_ID,
+			exponent: 0,
+			maxStaleness: new anchor.BN(60),
+			sampling: {
+				window: new anchor.BN(3600),
+				minInterval: new anchor.BN(60),
+				minSamples: 1,
+				aggregation: { timeWeightedAverage: {} },
+			},
+		};
+
+		await proposeMarket(sampledMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(MOCK_PRICE_PUBLISH_TIME),
+			resolutionDeadline: new anchor.BN(MOCK_PRICE_PUBLISH_TIME + 3600),
+			oracle,
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: sampledMarket.marketConfig,
+				marketState: sampledMarket.marketState,
+				marketVault: sampledMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await program.methods
+			.initializePriceSamples()
+			.accountsStrict({
+				payer: user.publicKey,
+				platformConfig,
+				marketConfig: sampledMarket.marketConfig,
+				priceSamples,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const pushSample = () =>
+			program.methods
+				.pushPriceSample()
+				.accountsStrict({
+					keeper: user.publicKey,
+					platformConfig,
+					marketConfig: sampledMarket.marketConfig,
+					marketState: sampledMarket.marketState,
+					priceSamples,
+					priceUpdate: MOCK_PRICE_UPDATE,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([user])
+				.rpc();
+
+		await pushSample();
+
+		// The feed has not published again, so the same price cannot be sampled twice
+		let rateError: anchor.AnchorError | undefined;
+		try {
+			await pushSample();
+		} catch (err) {
+			rateError = err;
+		}
+		expect(rateError?.error.errorCode.code).to.equal("SampleTooSoon");
+
+		const priceSamplesAccount = await program.account.priceSamples.fetch(
+			priceSamples
+		);
+		expect(priceSamplesAccount.samples).to.have.lengthOf(1);
+		expect(priceSamplesAccount.samples[0].price.toNumber()).to.equal(150);
+		expect(priceSamplesAccount.lastPublishTime.toNumber()).to.equal(
+			MOCK_PRICE_PUBLISH_TIME
+		);
+	});
+
+	it("should resolve a market to the committee median", async () => {
+		const updateCommittee = (
+			members: anchor.web3.PublicKey[],
+			threshold: number
+		) =>
+			program.methods
+				.updateResolverCommittee(members, threshold, null)
+				.accountsStrict({
+					admin: admin.publicKey,
+					platformConfig,
+					resolverCommittee,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+
+		// The admin holds the platform resolver role, which resolves directly
+		const member = await generateAndAirdropSigner(provider);
+		const removedMember = await generateAndAirdropSigner(provider);
+		await updateCommittee(
+			[member.publicKey, creator.publicKey, removedMember.publicKey],
+			3
+		);
+
+		const committeeMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const committeeMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			committeeMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(committeeMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 2),
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: committeeMarket.marketConfig,
+				marketState: committeeMarket.marketState,
+				marketVault: committeeMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the market to end
+
+		const attest = (member: anchor.web3.Keypair, value: number) =>
+			program.methods
+				.resolveMarket(new anchor.BN(value))
+				.accountsStrict({
+					resolver: member.publicKey,
+					resolverCommittee,
+					marketConfig: committeeMarket.marketConfig,
+					marketState: committeeMarket.marketState,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([member])
+				.rpc();
+
+		// A member removed after attesting no longer counts towards the threshold
+		await attest(removedMember, 999);
+		await updateCommittee(
+			[member.publicKey, creator.publicKey, user.publicKey],
+			3
+		);
+		await attest(member, 150);
+		await attest(creator, 151);
+
+		let marketStateAccount = await program.account.marketState.fetch(
+			committeeMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ closed: {} });
+
+		await attest(user, 300);
+
+		marketStateAccount = await program.account.marketState.fetch(
+			committeeMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ resolved: {} });
+		expect(marketStateAccount.resolution.toNumber()).to.equal(151);
+		expect(
+			marketStateAccount.attestations.map((a) => a.member.toBase58())
+		).to.deep.equal([
+			member.publicKey.toBase58(),
+			creator.publicKey.toBase58(),
+			user.publicKey.toBase58(),
+		]);
+		expect(
+			marketStateAccount.attestations.map((a) => a.isFlagged)
+		).to.deep.equal([false, false, true]);
+
+		// The outlier's flag is recorded against the member on the committee
+		const committeeAccount = await program.account.resolverCommittee.fetch(
+			resolverCommittee
+		);
+		expect(committeeAccount.flagCounts).to.deep.equal([0, 0, 1]);
+
+		await updateCommittee([admin.publicKey], 1);
+	});
+
+	it("should resolve a market by its designated resolver", async () => {
+		const resolverMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const resolverMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			resolverMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(resolverMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 2),
+		});
+
+		await program.methods
+			.approveMarket(creator.publicKey, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: resolverMarket.marketConfig,
+				marketState: resolverMarket.marketState,
+				marketVault: resolverMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the market to end
+
+		// The platform resolver has to wait out the grace period
+		let graceError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.resolveMarket(resolution)
+				.accountsStrict({
+					resolver: admin.publicKey,
+					resolverCommittee,
+					marketConfig: resolverMarket.marketConfig,
+					marketState: resolverMarket.marketState,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			graceError = err;
+		}
+		expect(graceError?.error.errorCode.code).to.equal("ResolverGracePeriod");
+
+		// The committee account is only needed for attestations
+		await program.methods
+			.resolveMarket(resolution)
+			.accountsStrict({
+				resolver: creator.publicKey,
+				resolverCommittee: null,
+				marketConfig: resolverMarket.marketConfig,
+				marketState: resolverMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([creator])
+			.rpc();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			resolverMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ resolved: {} });
+		expect(marketStateAccount.resolution.toNumber()).to.equal(
+			resolution.toNumber()
+		);
+		expect(marketStateAccount.attestations).to.be.empty;
+	});
+
+	it("should finalize an unchallenged creator resolution", async () => {
+		const bondedMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const bondedMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			bondedMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(bondedMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 2),
+			creatorBond,
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: bondedMarket.marketConfig,
+				marketState: bondedMarket.marketState,
+				marketVault: bondedMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the market to end
+		const signature = await program.methods
+			.proposeResolution(resolution)
+			.accountsStrict({
+				creator: creator.publicKey,
+				marketConfig: bondedMarket.marketConfig,
+				marketState: bondedMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([creator])
+			.rpc();
+
+		const [event] = await fetchCpiEvents(provider, program, signature);
+		expect(event.name).to.equal("resolutionProposed");
+		expect(event.data.resolution.toNumber()).to.equal(resolution.toNumber());
+
+		const creatorBalanceBefore = await provider.connection.getBalance(
+			creator.publicKey
+		);
+
+		// With no dispute period the liveness period is over right away
+		await program.methods
+			.finalizeResolution()
+			.accountsStrict({
+				signer: user.publicKey,
+				creator: creator.publicKey,
+				marketConfig: bondedMarket.marketConfig,
+				marketState: bondedMarket.marketState,
+				marketVault: bondedMarket.marketVault,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const creatorBalanceAfter = await provider.connection.getBalance(
+			creator.publicKey
+		);
+		expect(creatorBalanceAfter - creatorBalanceBefore).to.equal(
+			creatorBond.toNumber()
+		);
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			bondedMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ resolved: {} });
+		expect(marketStateAccount.creatorBondLocked).to.be.false;
+		expect(marketStateAccount.resolution.toNumber()).to.equal(
+			resolution.toNumber()
+		);
+	});
+
+	it("should slash the creator bond on a successful challenge", async () => {
+		const setDisputePeriod = (period: anchor.BN) =>
+			program.methods
+				.updatePlatformConfig(
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					period,
+					null,
+					null,
+					null,
+					null
+				)
+				.accountsStrict({
+					authority: admin.publicKey,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+
+		await setDisputePeriod(new anchor.BN(60));
+
+		const bondedMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const bondedMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			bondedMarketId
+		);
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(bondedMarketId, {
+			startTime: new anchor.BN(now + 1),
+			endTime: new anchor.BN(now + 2),
+			creatorBond,
+		});
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: bondedMarket.marketConfig,
+				marketState: bondedMarket.marketState,
+				marketVault: bondedMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 2500)); // Wait the market to end
+		await program.methods
+			.proposeResolution(resolution)
+			.accountsStrict({
+				creator: creator.publicKey,
+				marketConfig: bondedMarket.marketConfig,
+				marketState: bondedMarket.marketState,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([creator])
+			.rpc();
+
+		const challengedResolution = new anchor.BN(145);
+		await program.methods
+			.challengeResolution(challengedResolution)
+			.accountsStrict({
+				challenger: user.publicKey,
+				marketConfig: bondedMarket.marketConfig,
+				marketState: bondedMarket.marketState,
+				marketVault: bondedMarket.marketVault,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const userBalanceBefore = await provider.connection.getBalance(
+			user.publicKey
+		);
+
+		await program.methods
+			.settleResolutionChallenge(true)
+			.accountsStrict({
+				arbiter: admin.publicKey,
+				creator: creator.publicKey,
+				challenger: user.publicKey,
+				platformConfig,
+				platformTreasury,
+				marketConfig: bondedMarket.marketConfig,
+				marketState: bondedMarket.marketState,
+				marketVault: bondedMarket.marketVault,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		// The challenger gets their bond back and half the creator bond
+		const userBalanceAfter = await provider.connection.getBalance(
+			user.publicKey
+		);
+		expect(userBalanceAfter - userBalanceBefore).to.equal(
+			disputeBond.toNumber() + creatorBond.toNumber() / 2
+		);
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			bondedMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ resolved: {} });
+		expect(marketStateAccount.resolution.toNumber()).to.equal(
+			challengedResolution.toNumber()
+		);
+
+		await setDisputePeriod(disputePeriod);
+	});
+
+	it("should place a prediction in a token market", async () => {
+		const stakeMint = await createStakeMint(provider, creator, 6);
+		const tokenStake = new anchor.BN(10_000_000);
+		const userTokenAccount = await createTokenAccount(
+			provider,
+			user,
+			stakeMint,
+			user.publicKey
+		);
+		const treasuryTokenAccount = await createTokenAccount(
+			provider,
+			admin,
+			stakeMint,
+			platformTreasury
+		);
+		await mintStakeTokens(
+			provider,
+			creator,
+			stakeMint,
+			userTokenAccount,
+			tokenStake
+		);
+
+		const tokenMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const tokenMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			tokenMarketId
+		);
+		const vaultTokenAccount = anchor.utils.token.associatedAddress({
+			mint: stakeMint,
+			owner: tokenMarket.marketVault,
+		});
+		const now = new Date().getTime() / 1000;
+
+		await proposeMarket(tokenMarketId, {
+			startTime: new anchor.BN(now + 1),
+			minPredictionPrice: new anchor.BN(1_000_000),
+			stakeMint,
+		});
+
+		const marketConfigAccount = await program.account.marketConfig.fetch(
+			tokenMarket.marketConfig
+		);
+		expect(marketConfigAccount.stakeMint.toBase58()).to.equal(
+			stakeMint.toBase58()
+		);
+
+		await program.methods
+			.approveMarket(null, 0)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: tokenMarket.marketConfig,
+				marketState: tokenMarket.marketState,
+				marketVault: tokenMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		await new Promise((resolve) => setTimeout(resolve, 1500)); // Wait the market to start
+		const tokenPosition = findPosition(
+			program.programId,
+			new anchor.BN(0),
+			user.publicKey,
+			tokenMarket.marketConfig
+		);
+
+		await program.methods
+			.placePrediction(prediction, tokenStake)
+			.accountsStrict({
+				user: user.publicKey,
+				position: tokenPosition,
+				platformConfig,
+				...tokenMarket,
+				stakeMint,
+				userTokenAccount,
+				vaultTokenAccount,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([user])
+			.rpc();
+
+		const platformFee = (tokenStake.toNumber() * platformFeeBps) / 10000;
+		const creatorRevenue = (tokenStake.toNumber() * creatorFeeBps) / 10000;
+
+		const positionAccount = await program.account.position.fetch(
+			tokenPosition
+		);
+		expect(positionAccount.stake.toNumber()).to.equal(
+			tokenStake.toNumber() - platformFee - creatorRevenue
+		);
+		// The platform fee stays escrowed in the vault until the market resolves
+		expect(await tokenBalance(provider, treasuryTokenAccount)).to.equal(0);
+		expect(await tokenBalance(provider, vaultTokenAccount)).to.equal(
+			tokenStake.toNumber()
+		);
+		const marketStateAccount = await program.account.marketState.fetch(
+			tokenMarket.marketState
+		);
+		expect(marketStateAccount.platformFeeRevenue.toNumber()).to.equal(
+			platformFee
+		);
+	});
+
+	it("should dismiss a market", async () => {
+		const newMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+
+		const newStartTime = new anchor.BN(new Date().getTime() / 1000 + 1);
+
+		const [newMarketConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("market-config"),
+				newMarketId.toBuffer("le", 8),
+				platformConfig.toBuffer(),
+			],
+			program.programId
+		);
+
+		const [newMarketState] = anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("market-state"),
+				newMarketConfig.toBuffer(),
+				platformConfig.toBuffer(),
+			],
+			program.programId
+		);
+
+		const [newMarketVault] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("market-vault"), newMarketConfig.toBuffer()],
+			program.programId
+		);
+
+		await proposeMarket(newMarketId, {
+			startTime: newStartTime,
+		});
+
+		const treasuryBalanceBefore = await provider.connection.getBalance(
+			platformTreasury
+		);
+
+		await program.methods
+			.dismissMarket({ duplicate: {} })
+			.accountsStrict({
+				approver: admin.publicKey,
+				creator: creator.publicKey,
+				marketConfig: newMarketConfig,
+				marketState: newMarketState,
+				marketVault: newMarketVault,
+				marketTombstone: findMarketTombstone(
+					program.programId,
+					platformConfig,
+					newMarketId
+				),
+				platformConfig,
+				platformTreasury,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				creatorTokenAccount: null,
+				tokenProgram: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		expect(await provider.connection.getAccountInfo(newMarketConfig)).to.be
+			.null;
+		expect(await provider.connection.getAccountInfo(newMarketState)).to.be
+			.null;
+
+		// The treasury keeps the share of the escrowed proposal fee that is not refunded
+		const proposalFeeRefund = Math.floor(
+			(marketProposalFee.toNumber() * dismissalRefundBps) / 10000
+		);
+		expect(await provider.connection.getBalance(platformTreasury)).to.equal(
+			treasuryBalanceBefore + marketProposalFee.toNumber() - proposalFeeRefund
+		);
+		expect(await provider.connection.getBalance(newMarketVault)).to.equal(0);
+
+		const marketTombstone = findMarketTombstone(
+			program.programId,
+			platformConfig,
+			newMarketId
+		);
+		const tombstoneAccount = await program.account.marketTombstone.fetch(
+			marketTombstone
+		);
+		expect(tombstoneAccount.marketId.toString()).to.equal(
+			newMarketId.toString()
+		);
+		expect(tombstoneAccount.reason).to.deep.equal({ duplicate: {} });
+		expect(tombstoneAccount.creator.toBase58()).to.equal(
+			creator.publicKey.toBase58()
+		);
+
+		// The dismissed market ID cannot be proposed again
+		let reuseError: anchor.AnchorError | undefined;
+		try {
+			await proposeMarket(newMarketId, {
+				startTime: new anchor.BN(new Date().getTime() / 1000 + 1),
+			});
+		} catch (err) {
+			reuseError = err;
+		}
+		expect(reuseError?.error.errorCode.code).to.equal("MarketIdDismissed");
+	});
+
+	it("should withdraw creator revenue", async () => {
+		await program.methods
+			.withdrawCreatorRevenue()
+			.accountsStrict({
+				creator: creator.publicKey,
+				marketConfig,
+				marketState,
+				marketVault,
+				platformConfig,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				creatorTokenAccount: null,
+				tokenProgram: null,
+				rent: RENT_SYSVAR_ACCOUNT,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([creator])
+			.rpc();
+	});
+
+	it("should let the creator withdraw a proposal", async () => {
+		const withdrawnMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const withdrawnMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			withdrawnMarketId
+		);
+		const marketTombstone = findMarketTombstone(
+			program.programId,
+			platformConfig,
+			withdrawnMarketId
+		);
+
+		await proposeMarket(withdrawnMarketId, {
+			startTime: new anchor.BN(new Date().getTime() / 1000 + 1),
+		});
+
+		// Only the creator can withdraw their proposal
+		let withdrawError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.withdrawProposal()
+				.accountsStrict({
+					creator: user.publicKey,
+					platformConfig,
+					platformTreasury,
+					...withdrawnMarket,
+					stakeMint: null,
+					vaultTokenAccount: null,
+					creatorTokenAccount: null,
+					tokenProgram: null,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([user])
+				.rpc();
+		} catch (err) {
+			withdrawError = err;
+		}
+		expect(withdrawError?.error.errorCode.code).to.equal("Unauthorized");
+
+		const treasuryBalanceBefore = await provider.connection.getBalance(
+			platformTreasury
+		);
+
+		await program.methods
+			.withdrawProposal()
+			.accountsStrict({
+				creator: creator.publicKey,
+				platformConfig,
+				platformTreasury,
+				...withdrawnMarket,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				creatorTokenAccount: null,
+				tokenProgram: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([creator])
+			.rpc();
+
+		expect(
+			await provider.connection.getAccountInfo(withdrawnMarket.marketConfig)
+		).to.be.null;
+		expect(
+			await provider.connection.getAccountInfo(withdrawnMarket.marketState)
+		).to.be.null;
+		expect(
+			await provider.connection.getBalance(withdrawnMarket.marketVault)
+		).to.equal(0);
+
+		// The treasury keeps the share of the proposal fee that is not refunded
+		const proposalFeeRefund = Math.floor(
+			(marketProposalFee.toNumber() * withdrawalRefundBps) / 10000
+		);
+		expect(await provider.connection.getBalance(platformTreasury)).to.equal(
+			treasuryBalanceBefore + marketProposalFee.toNumber() - proposalFeeRefund
+		);
+
+		// A withdrawn market leaves no tombstone, so its ID can be proposed again
+		expect(await provider.connection.getAccountInfo(marketTombstone)).to.be
+			.null;
+	});
+
+	it("should let the creator edit a pending proposal", async () => {
+		const editedMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const editedMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			editedMarketId
+		);
+
+		await proposeMarket(editedMarketId, {
+			startTime: new anchor.BN(new Date().getTime() / 1000 + 60),
+		});
+
+		const fixedQuestion = "What will be the price of SOL at market close?";
+		await program.methods
+			.updateMarketConfig(
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
+				fixedQuestion,
+				null
+			)
+			.accountsStrict({
+				authority: creator.publicKey,
+				platformConfig,
+				marketConfig: editedMarket.marketConfig,
+				marketState: editedMarket.marketState,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([creator])
+			.rpc();
+
+		const marketConfigAccount = await program.account.marketConfig.fetch(
+			editedMarket.marketConfig
+		);
+		expect(marketConfigAccount.question).to.equal(fixedQuestion);
+		expect(marketConfigAccount.revision).to.equal(1);
+
+		// The scoring parameters stay with the admin
+		let editError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.updateMarketConfig(
+					null,
+					null,
+					null,
+					null,
+					null,
+					sigmaMax.muln(2),
+					null,
+					null,
+					null,
+					null,
+					null
+				)
+				.accountsStrict({
+					authority: creator.publicKey,
+					platformConfig,
+					marketConfig: editedMarket.marketConfig,
+					marketState: editedMarket.marketState,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([creator])
+				.rpc();
+		} catch (err) {
+			editError = err;
+		}
+		expect(editError?.error.errorCode.code).to.equal("CreatorEditNotAllowed");
+
+		// Only the admin and the creator can edit the proposal
+		let unauthorizedError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.updateMarketConfig(
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					"Not my market"
+				)
+				.accountsStrict({
+					authority: user.publicKey,
+					platformConfig,
+					marketConfig: editedMarket.marketConfig,
+					marketState: editedMarket.marketState,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([user])
+				.rpc();
+		} catch (err) {
+			unauthorizedError = err;
+		}
+		expect(unauthorizedError?.error.errorCode.code).to.equal("Unauthorized");
+
+		// The admin reviewed the original revision, which is now stale
+		let revisionError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.approveMarket(null, 0)
+				.accountsStrict({
+					approver: admin.publicKey,
+					marketConfig: editedMarket.marketConfig,
+					marketState: editedMarket.marketState,
+					marketVault: editedMarket.marketVault,
+					platformConfig,
+					platformTreasury,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			revisionError = err;
+		}
+		expect(revisionError?.error.errorCode.code).to.equal("RevisionMismatch");
+
+		await program.methods
+			.approveMarket(null, 1)
+			.accountsStrict({
+				approver: admin.publicKey,
+				marketConfig: editedMarket.marketConfig,
+				marketState: editedMarket.marketState,
+				marketVault: editedMarket.marketVault,
+				platformConfig,
+				platformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			editedMarket.marketState
+		);
+		expect(marketStateAccount.status).to.deep.equal({ approved: {} });
+	});
+
+	it("should collect a market's platform fees", async () => {
+		const treasuryBalanceBefore = await provider.connection.getBalance(
+			platformTreasury
+		);
+		const { platformFeeRevenue } = await program.account.marketState.fetch(
+			marketState
+		);
+
+		const signature = await program.methods
+			.collectPlatformFees()
+			.accountsStrict({
+				treasurer: admin.publicKey,
+				platformConfig,
+				platformTreasury,
+				marketConfig,
+				marketState,
+				marketVault,
+				stakeMint: null,
+				vaultTokenAccount: null,
+				treasuryTokenAccount: null,
+				tokenProgram: null,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const [event] = await fetchCpiEvents(provider, program, signature);
+		expect(event.name).to.equal("platformFeesCollected");
+		expect(event.data.amount.toNumber()).to.equal(
+			platformFeeRevenue.toNumber()
+		);
+
+		const treasuryBalanceAfter = await provider.connection.getBalance(
+			platformTreasury
+		);
+		expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(
+			platformFeeRevenue.toNumber()
+		);
+
+		const marketStateAccount = await program.account.marketState.fetch(
+			marketState
+		);
+		expect(marketStateAccount.platformFeeRevenue.toNumber()).to.equal(0);
+	});
+
+	it("should withdraw platform fees", async () => {
+		await program.methods
+			.withdrawPlatformFees()
+			.accountsStrict({
+				treasurer: admin.publicKey,
+				platformConfig,
+				platformTreasury,
+				stakeMint: null,
+				treasuryTokenAccount: null,
+				treasurerTokenAccount: null,
+				tokenProgram: null,
+				rent: RENT_SYSVAR_ACCOUNT,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+	});
+
+	it("should update platform config", async () => {
+		const newCreatorFeeBps = 100;
+		const newMarketProposalFee = new anchor.BN(
+			0.001 * anchor.web3.LAMPORTS_PER_SOL
+		);
+		const newSpamDismissalRefundBps = 1000;
+		await program.methods
+			.updatePlatformConfig(
+				newCreatorFeeBps,
+				null,
+				newMarketProposalFee,
+				null,
+				newSpamDismissalRefundBps,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null
+			)
+			.accountsStrict({
+				authority: admin.publicKey,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		const platformConfigAccount =
 			await program.account.platformConfig.fetch(platformConfig);
 		expect(platformConfigAccount.platformFeeBps).to.equal(platformFeeBps); // Unchanged
 		expect(platformConfigAccount.creatorFeeBps).to.equal(newCreatorFeeBps);
 		expect(platformConfigAccount.marketProposalFee.toNumber()).to.equal(
 			newMarketProposalFee.toNumber()
 		);
+		expect(platformConfigAccount.dismissalRefundBps).to.equal(
+			dismissalRefundBps
+		); // Unchanged
+		expect(platformConfigAccount.spamDismissalRefundBps).to.equal(
+			newSpamDismissalRefundBps
+		);
+	});
+
+	it("should split duties across roles", async () => {
+		const pauser = await generateAndAirdropSigner(provider);
+		const treasurer = await generateAndAirdropSigner(provider);
+
+		for (const [role, holder] of [
+			[{ pauser: {} }, pauser],
+			[{ treasurer: {} }, treasurer],
+		] as const) {
+			await program.methods
+				.grantRole(role, holder.publicKey)
+				.accountsStrict({
+					admin: admin.publicKey,
+					platformConfig,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		}
+
+		let platformConfigAccount =
+			await program.account.platformConfig.fetch(platformConfig);
+		expect(platformConfigAccount.pauser.toBase58()).to.equal(
+			pauser.publicKey.toBase58()
+		);
+		expect(platformConfigAccount.treasurer.toBase58()).to.equal(
+			treasurer.publicKey.toBase58()
+		);
+
+		// Only role holders can act, and the admin no longer holds these roles
+		let pauseError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.setPaused(true)
+				.accountsStrict({
+					pauser: admin.publicKey,
+					platformConfig,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			pauseError = err;
+		}
+		expect(pauseError?.error.errorCode.code).to.equal("Unauthorized");
+
+		let feeError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.updatePlatformConfig(
+					null,
+					platformFeeBps + 1,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null,
+					null
+				)
+				.accountsStrict({
+					authority: admin.publicKey,
+					platformConfig,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			feeError = err;
+		}
+		expect(feeError?.error.errorCode.code).to.equal("Unauthorized");
+
+		await program.methods
+			.updatePlatformConfig(
+				null,
+				platformFeeBps + 1,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
+				null
+			)
+			.accountsStrict({
+				authority: treasurer.publicKey,
+				platformConfig,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([treasurer])
+			.rpc();
+
+		// A paused platform takes no new proposals and approves none of the pending ones
+		const pendingMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const pendingMarket = findMarketAccounts(
+			program.programId,
+			platformConfig,
+			pendingMarketId
+		);
+		await proposeMarket(pendingMarketId, {
+			startTime: new anchor.BN(new Date().getTime() / 1000 + 60),
+		});
+
+		await program.methods
+			.setPaused(true)
+			.accountsStrict({
+				pauser: pauser.publicKey,
+				platformConfig,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([pauser])
+			.rpc();
+
+		const pausedMarketId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		let proposeError: anchor.AnchorError | undefined;
+		try {
+			await proposeMarket(pausedMarketId, {
+				startTime: new anchor.BN(new Date().getTime() / 1000 + 60),
+			});
+		} catch (err) {
+			proposeError = err;
+		}
+		expect(proposeError?.error.errorCode.code).to.equal("PlatformPaused");
+
+		let approveError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.approveMarket(null, 0)
+				.accountsStrict({
+					approver: admin.publicKey,
+					marketConfig: pendingMarket.marketConfig,
+					marketState: pendingMarket.marketState,
+					marketVault: pendingMarket.marketVault,
+					platformConfig,
+					platformTreasury,
+					systemProgram: SYSTEM_PROGRAM_ID,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			approveError = err;
+		}
+		expect(approveError?.error.errorCode.code).to.equal("PlatformPaused");
+
+		await program.methods
+			.setPaused(false)
+			.accountsStrict({
+				pauser: pauser.publicKey,
+				platformConfig,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([pauser])
+			.rpc();
+
+		// Revoked roles go back to the admin
+		await program.methods
+			.revokeRole({ treasurer: {} })
+			.accountsStrict({
+				admin: admin.publicKey,
+				platformConfig,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		platformConfigAccount =
+			await program.account.platformConfig.fetch(platformConfig);
+		expect(platformConfigAccount.treasurer.toBase58()).to.equal(
+			admin.publicKey.toBase58()
+		);
+		expect(platformConfigAccount.platformFeeBps).to.equal(platformFeeBps + 1);
+		expect(platformConfigAccount.isPaused).to.be.false;
+	});
+
+	it("should migrate an admin-keyed platform config", async () => {
+		const airdropSignature = await provider.connection.requestAirdrop(
+			LEGACY_ADMIN.publicKey,
+			anchor.web3.LAMPORTS_PER_SOL
+		);
+		await provider.connection.confirmTransaction(airdropSignature, "confirmed");
+
+		const [legacyPlatformConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("platform-config"), LEGACY_ADMIN.publicKey.toBuffer()],
+			program.programId
+		);
+		const [legacyPlatformTreasury] =
+			anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("platform-treasury"), legacyPlatformConfig.toBuffer()],
+				program.programId
+			);
+
+		const migratedPlatformId = new anchor.BN(
+			Math.floor(Math.random() * 1e17).toString()
+		);
+		const [migratedPlatformConfig] =
+			anchor.web3.PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("platform-config"),
+					migratedPlatformId.toBuffer("le", 8),
+				],
+				program.programId
+			);
+		const [migratedPlatformTreasury] =
+			anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("platform-treasury"), migratedPlatformConfig.toBuffer()],
+				program.programId
+			);
+
+		const legacyTreasuryBalance = await provider.connection.getBalance(
+			legacyPlatformTreasury
+		);
+		expect(legacyTreasuryBalance).to.equal(2 * anchor.web3.LAMPORTS_PER_SOL);
+
+		const signature = await program.methods
+			.migratePlatform(migratedPlatformId)
+			.accountsStrict({
+				admin: LEGACY_ADMIN.publicKey,
+				legacyPlatformConfig,
+				legacyPlatformTreasury,
+				platformConfig: migratedPlatformConfig,
+				platformTreasury: migratedPlatformTreasury,
+				systemProgram: SYSTEM_PROGRAM_ID,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([LEGACY_ADMIN])
+			.rpc();
+
+		const [event] = await fetchCpiEvents(provider, program, signature);
+		expect(event.name).to.equal("platformMigrated");
+		expect(event.data.treasuryBalance.toNumber()).to.equal(
+			legacyTreasuryBalance
+		);
+
+		// The fee settings carry over, and the markets keep their addresses
+		const platformConfigAccount = await program.account.platformConfig.fetch(
+			migratedPlatformConfig
+		);
+		expect(platformConfigAccount.platformId.toString()).to.equal(
+			migratedPlatformId.toString()
+		);
+		expect(platformConfigAccount.creatorFeeBps).to.equal(200);
+		expect(platformConfigAccount.platformFeeBps).to.equal(300);
+		expect(platformConfigAccount.marketProposalFee.toNumber()).to.equal(
+			100_000_000
+		);
+		expect(platformConfigAccount.disputePeriod.toNumber()).to.equal(0);
+		expect(
+			platformConfigAccount.settlementGracePeriod.toNumber()
+		).to.equal(7 * 24 * 60 * 60);
+		expect(platformConfigAccount.admin.toBase58()).to.equal(
+			LEGACY_ADMIN.publicKey.toBase58()
+		);
+		expect(platformConfigAccount.treasurer.toBase58()).to.equal(
+			LEGACY_ADMIN.publicKey.toBase58()
+		);
+		expect(platformConfigAccount.namespace.toBase58()).to.equal(
+			legacyPlatformConfig.toBase58()
+		);
+
+		// The treasury balance moved to the new config's treasury
+		expect(
+			await provider.connection.getBalance(migratedPlatformTreasury)
+		).to.equal(legacyTreasuryBalance);
+		expect(
+			await provider.connection.getBalance(legacyPlatformTreasury)
+		).to.equal(0);
+		expect(await provider.connection.getAccountInfo(legacyPlatformConfig)).to
+			.be.null;
+	});
+
+	it("should transfer the platform admin in two steps", async () => {
+		const newAdmin = await generateAndAirdropSigner(provider);
+
+		await program.methods
+			.proposeAdmin(newAdmin.publicKey)
+			.accountsStrict({
+				admin: admin.publicKey,
+				platformConfig,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([admin])
+			.rpc();
+
+		let platformConfigAccount =
+			await program.account.platformConfig.fetch(platformConfig);
+		expect(platformConfigAccount.pendingAdmin.toBase58()).to.equal(
+			newAdmin.publicKey.toBase58()
+		);
+		expect(platformConfigAccount.admin.toBase58()).to.equal(
+			admin.publicKey.toBase58()
+		); // Unchanged until accepted
+
+		let acceptError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.acceptAdmin()
+				.accountsStrict({
+					pendingAdmin: user.publicKey,
+					platformConfig,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([user])
+				.rpc();
+		} catch (err) {
+			acceptError = err;
+		}
+		expect(acceptError?.error.errorCode.code).to.equal("NotPendingAdmin");
+
+		await program.methods
+			.acceptAdmin()
+			.accountsStrict({
+				pendingAdmin: newAdmin.publicKey,
+				platformConfig,
+				eventAuthority,
+				program: program.programId,
+			})
+			.signers([newAdmin])
+			.rpc();
+
+		platformConfigAccount =
+			await program.account.platformConfig.fetch(platformConfig);
+		expect(platformConfigAccount.admin.toBase58()).to.equal(
+			newAdmin.publicKey.toBase58()
+		);
+		expect(platformConfigAccount.pendingAdmin).to.be.null;
+
+		// Roles the previous admin held move with the transfer, the others stay put
+		for (const holder of [
+			platformConfigAccount.approver,
+			platformConfigAccount.resolver,
+			platformConfigAccount.treasurer,
+			platformConfigAccount.arbiter,
+		]) {
+			expect(holder.toBase58()).to.equal(newAdmin.publicKey.toBase58());
+		}
+		expect(platformConfigAccount.pauser.toBase58()).to.not.equal(
+			newAdmin.publicKey.toBase58()
+		);
+
+		// The platform keeps its address, and the previous admin lost access
+		let unauthorizedError: anchor.AnchorError | undefined;
+		try {
+			await program.methods
+				.proposeAdmin(admin.publicKey)
+				.accountsStrict({
+					admin: admin.publicKey,
+					platformConfig,
+					eventAuthority,
+					program: program.programId,
+				})
+				.signers([admin])
+				.rpc();
+		} catch (err) {
+			unauthorizedError = err;
+		}
+		expect(unauthorizedError?.error.errorCode.code).to.equal("Unauthorized");
 	});
 });